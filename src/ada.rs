@@ -0,0 +1,77 @@
+use crate::internals::{cased_word, joined_words, split_delimited, validate_delimited, self, write_capitalized_delimited, CaseValue, Word};
+use crate::{Case, CaseError, ParseOptions, RenderOptions, WordCasing};
+use std::borrow::Borrow;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct AdaCase<'a>(pub(crate) CaseValue<'a>);
+
+impl<'a> Case<'a> for AdaCase<'a> {
+    #[inline]
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_delimited(source, "_", WordCasing::Capitalized)
+    }
+
+    #[inline]
+    fn str_as_case_unchecked(source: &'a str) -> Self {
+        AdaCase(CaseValue::Joined(source.into()))
+    }
+}
+
+impl<'a> internals::Case<'a> for AdaCase<'a> {
+    #[inline]
+    fn from_cased_words(words: Vec<Word<'a>>) -> Self {
+        AdaCase(CaseValue::Words(words))
+    }
+
+    #[inline]
+    fn to_cased_words(self) -> Vec<Word<'a>> {
+        match self.0 {
+            CaseValue::Words(words) => words,
+            CaseValue::Joined(string) => joined_words::<Self>(string),
+        }
+    }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        split_delimited(source, "_", options).map(cased_word)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        write_capitalized_delimited(words, f, '_', options)
+    }
+}
+
+impl<'a> fmt::Display for AdaCase<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match &self.0 {
+            CaseValue::Joined(string) => string.fmt(f),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
+        }
+    }
+}
+
+impl_str_ext! {
+    trait: AdaCaseExt,
+    case: AdaCase,
+    as: as_ada_case,
+    as_unchecked: as_ada_case_unchecked,
+    is: is_ada_case,
+    to: to_ada_case
+}
+
+impl_eq!(AdaCase);
+
+impl_into_owned!(AdaCase);
@@ -0,0 +1,116 @@
+use crate::{Case, ParseOptions};
+use std::fmt;
+
+/// Characters that may come before a name, such as the underscore of `_private` or the sigil of
+/// `$scope` or `@ivar`
+const PREFIX_CHARS: &[char] = &['_', '$', '@'];
+
+/// Characters that may come after a name, such as the trailing underscores of `__init__`
+const SUFFIX_CHARS: &[char] = &['_'];
+
+/// A case with a prefix and suffix that are kept as they are when it is converted. This is useful
+/// for names that carry underscores or sigils which are not part of any word, such as
+/// `_private_field`, `__dunder__`, `$scope` or `@ivar`.
+///
+/// ```
+/// use camel_kebab::{Affixed, CamelCase, SnakeCase};
+///
+/// let camel = Affixed::<CamelCase>::str_as_case("_fooBar").unwrap();
+/// assert_eq!("_foo_bar", camel.convert::<SnakeCase>().to_string());
+///
+/// let snake = Affixed::<SnakeCase>::str_as_case("__init__").unwrap();
+/// assert_eq!("__init__", snake.convert::<CamelCase>().to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Affixed<'a, C> {
+    pub prefix: &'a str,
+    pub core: C,
+    pub suffix: &'a str,
+}
+
+impl<'a, C: Case<'a>> Affixed<'a, C> {
+    /// Split any prefix and suffix from `source`, and check that what remains is in case `C`
+    #[inline]
+    pub fn str_as_case(source: &'a str) -> Option<Self> {
+        Affixed::str_as_case_with_options(source, ParseOptions::default())
+    }
+
+    /// Like `str_as_case`, but with control over how the words of `source` are found
+    pub fn str_as_case_with_options(source: &'a str, options: ParseOptions) -> Option<Self> {
+        let (prefix, core, suffix) = split_affixes(source);
+        C::str_as_case_with_options(core, options).map(|core| Affixed { prefix, core, suffix })
+    }
+
+    /// Convert the core to another case, keeping the prefix and suffix
+    #[inline]
+    pub fn convert<T: From<C>>(self) -> Affixed<'a, T> {
+        Affixed {
+            prefix: self.prefix,
+            core: T::from(self.core),
+            suffix: self.suffix,
+        }
+    }
+}
+
+impl<'a, C: fmt::Display> fmt::Display for Affixed<'a, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.prefix)?;
+        self.core.fmt(f)?;
+        f.write_str(self.suffix)
+    }
+}
+
+/// Split `source` into its prefix, core and suffix. If `source` is made only of affix characters,
+/// it is all prefix.
+fn split_affixes(source: &str) -> (&str, &str, &str) {
+    let rest = source.trim_start_matches(PREFIX_CHARS);
+    let core = rest.trim_end_matches(SUFFIX_CHARS);
+    let prefix = &source[..source.len() - rest.len()];
+    let suffix = &rest[core.len()..];
+    (prefix, core, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CamelCase, KebabCase, PascalCase, ScreamingSnakeCase, SnakeCase};
+
+    #[test]
+    fn test_split_affixes() {
+        assert_eq!(("_", "private_field", ""), split_affixes("_private_field"));
+        assert_eq!(("__", "dunder", "__"), split_affixes("__dunder__"));
+        assert_eq!(("$", "scope", ""), split_affixes("$scope"));
+        assert_eq!(("@@", "classVar", ""), split_affixes("@@classVar"));
+        assert_eq!(("", "type", "_"), split_affixes("type_"));
+        assert_eq!(("", "plain", ""), split_affixes("plain"));
+        assert_eq!(("__", "", ""), split_affixes("__"));
+        assert_eq!(("", "", ""), split_affixes(""));
+    }
+
+    #[test]
+    fn test_convert() {
+        let camel = Affixed::<CamelCase>::str_as_case("_fooBar").unwrap();
+        assert_eq!("_foo_bar", camel.convert::<SnakeCase>().to_string());
+
+        let snake = Affixed::<SnakeCase>::str_as_case("__init__").unwrap();
+        assert_eq!("__init__", snake.clone().convert::<SnakeCase>().to_string());
+        assert_eq!("__INIT__", snake.convert::<ScreamingSnakeCase>().to_string());
+
+        let camel = Affixed::<CamelCase>::str_as_case("$scopeName").unwrap();
+        assert_eq!("$ScopeName", camel.convert::<PascalCase>().to_string());
+
+        let kebab = Affixed::<KebabCase>::str_as_case("@ivar-name").unwrap();
+        assert_eq!("@ivar_name", kebab.convert::<SnakeCase>().to_string());
+
+        let screaming = Affixed::<ScreamingSnakeCase>::str_as_case("_PRIVATE_FIELD_").unwrap();
+        let camel = screaming.convert::<CamelCase>();
+        assert_eq!(("_", "_"), (camel.prefix, camel.suffix));
+        assert_eq!("_privateField_", camel.to_string());
+    }
+
+    #[test]
+    fn test_invalid_core() {
+        assert!(Affixed::<SnakeCase>::str_as_case("_fooBar").is_none());
+        assert!(Affixed::<CamelCase>::str_as_case("_foo__bar").is_none());
+    }
+}
@@ -1,5 +1,9 @@
-use crate::internals::{self, split_words_on_uppercase, write_pascal_case, CaseValue, Word};
-use crate::Case;
+use crate::internals::{
+    self, cased_word, hump_word, joined_words, split_words_on_uppercase, transliterate,
+    validate_humps, write_pascal_case, CaseValue, Word,
+};
+use crate::{Case, CaseError, ParseOptions, RenderOptions};
+use std::borrow::Borrow;
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -7,17 +11,13 @@ pub struct CamelCase<'a>(pub(crate) CaseValue<'a>);
 
 impl<'a> Case<'a> for CamelCase<'a> {
     #[inline]
-    fn str_is_case(source: &str) -> bool {
-        if let Some(first) = source.chars().next() {
-            first.is_lowercase() && source.chars().all(|ch| ch.is_alphanumeric())
-        } else {
-            true
-        }
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_humps(source, false)
     }
 
     #[inline]
     fn str_as_case_unchecked(source: &'a str) -> Self {
-        CamelCase(CaseValue::Joined(source))
+        CamelCase(CaseValue::Joined(source.into()))
     }
 }
 
@@ -31,16 +31,48 @@ impl<'a> internals::Case<'a> for CamelCase<'a> {
     fn to_cased_words(self) -> Vec<Word<'a>> {
         match self.0 {
             CaseValue::Words(words) => words,
-            CaseValue::Joined(string) => {
-                let mut words = split_words_on_uppercase(&string);
-                let mut vec = Vec::new();
-                if let Some(first) = words.next() {
-                    vec.push(Word::lower_case(first));
-                    vec.extend(words.map(|w| Word::capitalized(w)));
-                }
-                vec
-            }
+            CaseValue::Joined(string) => joined_words::<Self>(string),
+        }
+    }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        split_words_on_uppercase(source, options).map(hump_word)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        let mut words = words.into_iter();
+        if let Some(first_word) = words.next() {
+            write_first_word(first_word.borrow(), f, options)?;
+            write_pascal_case(words, f, options)?;
         }
+        Ok(())
+    }
+}
+
+/// Write the first word of camel case, which is the only word that can have a lowercase exception
+fn write_first_word<W>(word: &Word, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+where
+    W: fmt::Write,
+{
+    if options.ascii {
+        let ascii = transliterate(word.as_str());
+        let options = RenderOptions { ascii: false, ..*options };
+        return write_first_word(&cased_word(&ascii), f, &options);
+    }
+    match options.exceptions.and_then(|e| e.lowercase(word.as_str())) {
+        Some(spelling) => f.write_str(spelling),
+        None => word.write_lowercase_with(f, options),
     }
 }
 
@@ -49,12 +81,7 @@ impl<'a> fmt::Display for CamelCase<'a> {
         match &self.0 {
             CaseValue::Joined(string) => string.fmt(f),
             CaseValue::Words(words) => {
-                let mut words = words.iter();
-                if let Some(first_word) = words.next() {
-                    first_word.write_lowercase(f)?;
-                    write_pascal_case(words, f)?;
-                }
-                Ok(())
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
             }
         }
     }
@@ -65,7 +92,10 @@ impl_str_ext! {
     case: CamelCase,
     as: as_camel_case,
     as_unchecked: as_camel_case_unchecked,
-    is: is_camel_case
+    is: is_camel_case,
+    to: to_camel_case
 }
 
 impl_eq!(CamelCase);
+
+impl_into_owned!(CamelCase);
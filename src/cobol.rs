@@ -0,0 +1,77 @@
+use crate::internals::{joined_words, split_delimited, validate_delimited, self, write_upper_delimited, CaseValue, Word};
+use crate::{Case, CaseError, ParseOptions, RenderOptions, WordCasing};
+use std::borrow::Borrow;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct CobolCase<'a>(pub(crate) CaseValue<'a>);
+
+impl<'a> Case<'a> for CobolCase<'a> {
+    #[inline]
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_delimited(source, "-", WordCasing::Upper)
+    }
+
+    #[inline]
+    fn str_as_case_unchecked(source: &'a str) -> Self {
+        CobolCase(CaseValue::Joined(source.into()))
+    }
+}
+
+impl<'a> internals::Case<'a> for CobolCase<'a> {
+    #[inline]
+    fn from_cased_words(words: Vec<Word<'a>>) -> Self {
+        CobolCase(CaseValue::Words(words))
+    }
+
+    #[inline]
+    fn to_cased_words(self) -> Vec<Word<'a>> {
+        match self.0 {
+            CaseValue::Words(words) => words,
+            CaseValue::Joined(string) => joined_words::<Self>(string),
+        }
+    }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        split_delimited(source, "-", options).map(Word::upper_case)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        write_upper_delimited(words, f, '-', options)
+    }
+}
+
+impl<'a> fmt::Display for CobolCase<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match &self.0 {
+            CaseValue::Joined(string) => string.fmt(f),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
+        }
+    }
+}
+
+impl_str_ext! {
+    trait: CobolCaseExt,
+    case: CobolCase,
+    as: as_cobol_case,
+    as_unchecked: as_cobol_case_unchecked,
+    is: is_cobol_case,
+    to: to_cobol_case
+}
+
+impl_eq!(CobolCase);
+
+impl_into_owned!(CobolCase);
@@ -0,0 +1,216 @@
+//! Properties that every case type must have, checked against a shared table of samples.
+
+use crate::internals::{self, Word};
+use crate::*;
+use std::fmt::Display;
+
+const SAMPLES: &[&str] = &[
+    "",
+    "a",
+    "A",
+    "abc",
+    "ABC",
+    "Abc",
+    "aBC",
+    "AB",
+    "maxSize",
+    "MaxSize",
+    "max_size",
+    "MAX_SIZE",
+    "Max_Size",
+    "max-size",
+    "MAX-SIZE",
+    "Max-Size",
+    "max.size",
+    "max/size",
+    "max::size",
+    "maxsize",
+    "MAXSIZE",
+    "_max",
+    "max_",
+    "max__size",
+    "-max",
+    "max-",
+    "max--size",
+    "max size",
+    "max!",
+    "XMLHttpRequest",
+    "parseXMLDoc",
+    "HTTPServer",
+    "io2X",
+    "io_2_x",
+    "a_b_c",
+    "utf8Decoder",
+    "Vec3",
+    "Vec2d-X",
+    "Vec2d_X",
+    "1st_place",
+    "1stPlace",
+    "1st-Place",
+    "東京_tower",
+    "東京Tower",
+    "東京tower",
+    "こAんAにAちAはA",
+    "서울-station",
+    "cafe\u{301}_au_lait",
+    "Cafe\u{301}AuLait",
+    "CAFE\u{301}_AU_LAIT",
+    "ǆungla_walk",
+    "ǅungla-Walk",
+    "Ǆungla",
+    "straße",
+    "STRASSE",
+    "ß_x",
+    "İstanbul",
+    "ıi",
+    "ΣΟΦΟΣ_ΟΔΟΣ",
+    "σοφος",
+    "ETag",
+    "Etag",
+    "WWW-Authenticate",
+    "X-XSS-Protection",
+    "Content-MD5",
+    "Content-Type",
+    "content-type",
+];
+
+/// The default options, and each policy that differs from the default on its own
+fn parse_options() -> Vec<ParseOptions> {
+    let default = ParseOptions::default();
+    vec![
+        default,
+        default.acronyms(AcronymPolicy::SplitLetters),
+        default.digits(DigitPolicy::StartWord),
+        default.digits(DigitPolicy::Standalone),
+        default.scripts(ScriptPolicy::Split),
+    ]
+}
+
+/// Every way of asking whether `source` is in case `C` gives the same answer
+fn check_validation<C: Case<'static> + Display>(source: &'static str, options: ParseOptions) {
+    let valid = C::validate(source).is_ok();
+    assert_eq!(valid, C::str_is_case(source), "{:?}", source);
+    assert_eq!(valid, C::str_as_case(source).is_some(), "{:?}", source);
+    assert_eq!(valid, C::try_parse(source).is_ok(), "{:?}", source);
+    assert_eq!(
+        valid,
+        C::str_as_case_with_options(source, options).is_some(),
+        "{:?} with {:?}",
+        source,
+        options
+    );
+}
+
+/// A valid string displays as itself, and the words it is split into render as a valid string
+/// with the same words
+fn check_display<C: Case<'static> + Display>(source: &'static str, options: ParseOptions) {
+    let case = C::str_as_case(source).unwrap();
+    assert_eq!(source, case.to_string());
+
+    let parsed = C::str_as_case_with_options(source, options).unwrap();
+    let rendered = parsed.to_string();
+    assert!(C::str_is_case(&rendered), "{:?} renders as {:?}", source, rendered);
+    let words: Vec<_> = C::split_joined(source, options).collect();
+    let read_back: Vec<_> = C::split_joined(&rendered, options).collect();
+    assert!(
+        same_words(&words, &read_back),
+        "{:?} renders as {:?} with {:?}",
+        source,
+        rendered,
+        options
+    );
+    assert!(words.iter().all(Word::is_valid), "{:?} with {:?}", source, options);
+}
+
+fn same_words(a: &[Word], b: &[Word]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| internals::words_eq(a, b))
+}
+
+/// Render `source`, which is in case `A`, in case `B`
+fn render<A, B>(source: &str, options: ParseOptions) -> String
+where
+    A: Case<'static>,
+    B: Case<'static> + Display,
+{
+    let words: Vec<_> = A::split_joined(source, options).collect();
+    let mut rendered = String::new();
+    B::write_words(words, &mut rendered, &RenderOptions::default()).unwrap();
+    rendered
+}
+
+/// Converting to `B` gives a valid `B`, and converting back and forth settles after one round
+/// trip, even when the first conversion loses word boundaries
+fn check_conversion<A, B>(source: &'static str, options: ParseOptions)
+where
+    A: Case<'static> + Display,
+    B: Case<'static> + Display,
+{
+    let converted = render::<A, B>(source, options);
+    assert!(B::str_is_case(&converted), "{:?} converts to {:?}", source, converted);
+
+    let case = A::str_as_case_with_options(source, options).unwrap();
+    let mut streamed = String::new();
+    case.push_as::<B>(&mut streamed);
+    assert_eq!(converted, streamed, "{:?} with {:?}", source, options);
+    assert_eq!(converted.len(), case.len_as::<B>(), "{:?} with {:?}", source, options);
+
+    let once = render::<B, A>(&converted, options);
+    assert!(A::str_is_case(&once), "{:?} converts back to {:?}", converted, once);
+    let twice = render::<B, A>(&render::<A, B>(&once, options), options);
+    assert_eq!(once, twice, "{:?} via {:?} with {:?}", source, converted, options);
+}
+
+macro_rules! check_all {
+    ($($case: ty),+) => {
+        check_all!(@each [$($case),+] [$($case),+]);
+    };
+    (@each [$($from: ty),+] $to: tt) => {
+        for options in parse_options() {
+            for &source in SAMPLES {
+                $(
+                    check_validation::<$from>(source, options);
+                    if <$from>::str_is_case(source) {
+                        check_display::<$from>(source, options);
+                        check_all!(@to $from, source, options, $to);
+                    }
+                )+
+            }
+        }
+    };
+    (@to $from: ty, $source: ident, $options: ident, [$($to: ty),+]) => {
+        $(check_conversion::<$from, $to>($source, $options);)+
+    };
+}
+
+#[test]
+fn test_conformance() {
+    check_all!(
+        SnakeCase<'static>,
+        ScreamingSnakeCase<'static>,
+        KebabCase<'static>,
+        CobolCase<'static>,
+        CamelCase<'static>,
+        PascalCase<'static>,
+        AdaCase<'static>,
+        TrainCase<'static>,
+        HttpHeaderCase<'static>,
+        DotCase<'static>,
+        PathCase<'static>,
+        DelimitedCase<'static, DoubleColon>,
+        FlatCase<'static>,
+        UpperFlatCase<'static>
+    );
+}
+
+#[test]
+fn test_samples_are_valid_somewhere() {
+    let invalid: Vec<_> = SAMPLES
+        .iter()
+        .copied()
+        .filter(|source| !CaseKind::ALL.iter().any(|kind| kind.str_is_case(source)))
+        .collect();
+    let expected = [
+        "max::size", "_max", "max_", "max__size", "-max", "max-", "max--size", "max size", "max!",
+    ];
+    assert_eq!(&expected[..], &invalid[..]);
+}
@@ -0,0 +1,210 @@
+use crate::internals::{cased_word, joined_words, split_delimited, validate_delimited, self, write_delimited, CaseValue, Word};
+use crate::{Case, CaseError, ParseOptions, RenderOptions};
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// How each word of a `DelimitedCase` is cased.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WordCasing {
+    /// `lower.case`
+    Lower,
+    /// `UPPER.CASE`
+    Upper,
+    /// `Capitalized.Case`
+    Capitalized,
+}
+
+/// Describes the separator and word casing of a `DelimitedCase`.
+///
+/// ```
+/// use camel_kebab::{Delimiter, DelimitedCase, WordCasing};
+///
+/// struct Tilde;
+///
+/// impl Delimiter for Tilde {
+///     const SEPARATOR: &'static str = "~";
+///     const CASING: WordCasing = WordCasing::Upper;
+/// }
+///
+/// type TildeCase<'a> = DelimitedCase<'a, Tilde>;
+/// ```
+///
+/// A case with an empty separator fails to compile wherever it is used.
+///
+/// ```compile_fail
+/// use camel_kebab::{Case, Delimiter, DelimitedCase};
+///
+/// struct Nothing;
+///
+/// impl Delimiter for Nothing {
+///     const SEPARATOR: &'static str = "";
+/// }
+///
+/// DelimitedCase::<Nothing>::str_as_case("abc");
+/// ```
+pub trait Delimiter {
+    /// The separator between words. It may be more than one character, but must not be empty.
+    const SEPARATOR: &'static str;
+
+    const CASING: WordCasing = WordCasing::Lower;
+}
+
+/// `dot.case`
+#[derive(Debug, Copy, Clone)]
+pub struct Dot;
+
+impl Delimiter for Dot {
+    const SEPARATOR: &'static str = ".";
+}
+
+/// `path/case`
+#[derive(Debug, Copy, Clone)]
+pub struct Slash;
+
+impl Delimiter for Slash {
+    const SEPARATOR: &'static str = "/";
+}
+
+/// `double::colon::case`
+#[derive(Debug, Copy, Clone)]
+pub struct DoubleColon;
+
+impl Delimiter for DoubleColon {
+    const SEPARATOR: &'static str = "::";
+}
+
+/// `double__underscore__case`
+#[derive(Debug, Copy, Clone)]
+pub struct DoubleUnderscore;
+
+impl Delimiter for DoubleUnderscore {
+    const SEPARATOR: &'static str = "__";
+}
+
+pub type DotCase<'a> = DelimitedCase<'a, Dot>;
+pub type PathCase<'a> = DelimitedCase<'a, Slash>;
+
+/// Words joined by an arbitrary separator, with every word cased the same way. The separator and
+/// casing are given by the `Delimiter` type parameter.
+pub struct DelimitedCase<'a, D>(pub(crate) CaseValue<'a>, PhantomData<D>);
+
+impl<'a, D> DelimitedCase<'a, D> {
+    #[inline]
+    pub(crate) fn new(value: CaseValue<'a>) -> Self {
+        DelimitedCase(value, PhantomData)
+    }
+}
+
+impl<'a, D: Delimiter> DelimitedCase<'a, D> {
+    /// `D::SEPARATOR`, checked at compile time to be non-empty
+    const SEPARATOR: &'static str = {
+        assert!(!D::SEPARATOR.is_empty(), "the separator of a Delimiter must not be empty");
+        D::SEPARATOR
+    };
+
+    /// A copy of this value that does not borrow from the string it was made from
+    pub fn into_owned(self) -> DelimitedCase<'static, D> {
+        DelimitedCase::new(self.0.into_owned())
+    }
+}
+
+impl<'a, D> Clone for DelimitedCase<'a, D> {
+    fn clone(&self) -> Self {
+        DelimitedCase::new(self.0.clone())
+    }
+}
+
+impl<'a, D> fmt::Debug for DelimitedCase<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_tuple("DelimitedCase").field(&self.0).finish()
+    }
+}
+
+impl<'a, D: Delimiter> Case<'a> for DelimitedCase<'a, D> {
+    #[inline]
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_delimited(source, Self::SEPARATOR, D::CASING)
+    }
+
+    #[inline]
+    fn str_as_case_unchecked(source: &'a str) -> Self {
+        DelimitedCase::new(CaseValue::Joined(source.into()))
+    }
+}
+
+impl<'a, D: Delimiter> internals::Case<'a> for DelimitedCase<'a, D> {
+    #[inline]
+    fn from_cased_words(words: Vec<Word<'a>>) -> Self {
+        DelimitedCase::new(CaseValue::Words(words))
+    }
+
+    #[inline]
+    fn to_cased_words(self) -> Vec<Word<'a>> {
+        match self.0 {
+            CaseValue::Words(words) => words,
+            CaseValue::Joined(string) => joined_words::<Self>(string),
+        }
+    }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        let word: fn(&'s str) -> Word<'s> = match D::CASING {
+            WordCasing::Lower => Word::lower_case,
+            WordCasing::Upper => Word::upper_case,
+            WordCasing::Capitalized => |word| cased_word(word),
+        };
+        split_delimited(source, Self::SEPARATOR, options).map(word)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        write_delimited(words, f, Self::SEPARATOR, D::CASING, options)
+    }
+}
+
+impl<'a, D: Delimiter> fmt::Display for DelimitedCase<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match &self.0 {
+            CaseValue::Joined(string) => string.fmt(f),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
+        }
+    }
+}
+
+impl<'a, D: Delimiter> std::cmp::PartialEq for DelimitedCase<'a, D> {
+    fn eq(&self, other: &DelimitedCase<'a, D>) -> bool {
+        internals::display_eq(&self.to_string(), other)
+    }
+}
+
+impl<'a, D: Delimiter> std::cmp::Eq for DelimitedCase<'a, D> {}
+
+impl_str_ext! {
+    trait: DotCaseExt,
+    case: DotCase,
+    as: as_dot_case,
+    as_unchecked: as_dot_case_unchecked,
+    is: is_dot_case,
+    to: to_dot_case
+}
+
+impl_str_ext! {
+    trait: PathCaseExt,
+    case: PathCase,
+    as: as_path_case,
+    as_unchecked: as_path_case_unchecked,
+    is: is_path_case,
+    to: to_path_case
+}
@@ -0,0 +1,88 @@
+use crate::{CaseKind, CaseKindSet, ParseOptions, WordList};
+
+/// The result of `detect_case`
+#[derive(Debug, Clone)]
+pub struct Detection<'a> {
+    /// Every case that the string is valid in
+    pub matches: CaseKindSet,
+    /// The most likely of `matches`. For example, `foo` is valid snake, kebab and camel case, but
+    /// is most likely snake case.
+    pub best: CaseKind,
+    /// The words of the string, parsed according to `best`
+    pub words: WordList<'a>,
+}
+
+/// Find the cases that `source` is valid in. Returns `None` if it is not valid in any case.
+///
+/// ```
+/// use camel_kebab::{detect_case, CaseKind, SnakeCase};
+///
+/// let detection = detect_case("parseXmlDoc").unwrap();
+/// assert_eq!(CaseKind::Camel, detection.best);
+/// assert_eq!("parse_xml_doc", SnakeCase::from(detection.words).to_string());
+/// ```
+pub fn detect_case(source: &str) -> Option<Detection<'_>> {
+    let matches: CaseKindSet = CaseKind::ALL
+        .iter()
+        .copied()
+        .filter(|kind| kind.is(source))
+        .collect();
+    let best = matches.iter().next()?;
+    Some(Detection {
+        matches,
+        best,
+        words: best.split(source, ParseOptions::default()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SnakeCase;
+
+    fn best(source: &str) -> Option<CaseKind> {
+        detect_case(source).map(|detection| detection.best)
+    }
+
+    #[test]
+    fn test_detect_single_word_matches_many() {
+        let detection = detect_case("foo").unwrap();
+        let expected = [CaseKind::Snake, CaseKind::Kebab, CaseKind::Camel, CaseKind::Flat, CaseKind::Dot];
+        for &kind in &expected {
+            assert!(detection.matches.contains(kind), "{:?}", kind);
+        }
+        assert!(!detection.matches.contains(CaseKind::Pascal));
+        assert!(!detection.matches.contains(CaseKind::ScreamingSnake));
+        assert_eq!(CaseKind::Snake, detection.best);
+    }
+
+    #[test]
+    fn test_detect_best() {
+        assert_eq!(Some(CaseKind::Snake), best("foo_bar"));
+        assert_eq!(Some(CaseKind::ScreamingSnake), best("FOO_BAR"));
+        assert_eq!(Some(CaseKind::Kebab), best("foo-bar"));
+        assert_eq!(Some(CaseKind::Cobol), best("FOO-BAR"));
+        assert_eq!(Some(CaseKind::Camel), best("fooBar"));
+        assert_eq!(Some(CaseKind::Pascal), best("FooBar"));
+        assert_eq!(Some(CaseKind::Pascal), best("Foo"));
+        assert_eq!(Some(CaseKind::Ada), best("Foo_Bar"));
+        assert_eq!(Some(CaseKind::Train), best("Foo-Bar"));
+        assert_eq!(Some(CaseKind::HttpHeader), best("WWW-Authenticate"));
+        assert_eq!(Some(CaseKind::Dot), best("foo.bar"));
+        assert_eq!(Some(CaseKind::Path), best("foo/bar"));
+    }
+
+    #[test]
+    fn test_detect_none() {
+        assert!(detect_case("foo bar").is_none());
+        assert!(detect_case("foo_Bar-baz").is_none());
+    }
+
+    #[test]
+    fn test_detect_words() {
+        let snake: SnakeCase = detect_case("HTTPServer").unwrap().words.into();
+        assert_eq!("http_server", snake.to_string());
+        let snake: SnakeCase = detect_case("Content-Type").unwrap().words.into();
+        assert_eq!("content_type", snake.to_string());
+    }
+}
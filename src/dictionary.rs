@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+/// A list of known words, used to find word boundaries in text that has none, such as `flatcase`.
+/// Words are matched case-insensitively.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    words: HashSet<String>,
+    /// Length of the longest word, in chars
+    max_len: usize,
+}
+
+impl Dictionary {
+    pub fn new<I, S>(words: I) -> Dictionary
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut dictionary = Dictionary::default();
+        for word in words {
+            dictionary.insert(word.as_ref());
+        }
+        dictionary
+    }
+
+    pub fn insert(&mut self, word: &str) {
+        if !word.is_empty() {
+            self.max_len = self.max_len.max(word.chars().count());
+            self.words.insert(word.to_lowercase());
+        }
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    /// Split `source` into words, preferring the split that covers the most characters with
+    /// known words and, of those, the one with the fewest words. Consecutive characters that are
+    /// not part of any known word are kept together as a single word.
+    pub fn segment<'s>(&self, source: &'s str) -> Vec<&'s str> {
+        let bounds: Vec<usize> = source
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(Some(source.len()))
+            .collect();
+
+        // best[i] is the cost of segmenting the first i chars as (unknown chars, words), along
+        // with the start of the last word and whether it is known
+        let mut best: Vec<((usize, usize), usize, bool)> = Vec::with_capacity(bounds.len());
+        best.push(((0, 0), 0, true));
+        for end in 1..bounds.len() {
+            let ((unknown, words), _, _) = best[end - 1];
+            let mut candidate = ((unknown + 1, words + 1), end - 1, false);
+            for start in end.saturating_sub(self.max_len)..end {
+                let ((unknown, words), _, _) = best[start];
+                let cost = (unknown, words + 1);
+                if cost < candidate.0 && self.contains(&source[bounds[start]..bounds[end]]) {
+                    candidate = (cost, start, true);
+                }
+            }
+            best.push(candidate);
+        }
+
+        let mut segments = Vec::new();
+        let mut end = bounds.len() - 1;
+        while end > 0 {
+            let (_, mut start, known) = best[end];
+            if !known {
+                while start > 0 && !best[start].2 {
+                    start = best[start].1;
+                }
+            }
+            segments.push(&source[bounds[start]..bounds[end]]);
+            end = start;
+        }
+        segments.reverse();
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary() -> Dictionary {
+        Dictionary::new([
+            "file", "files", "system", "stem", "watcher", "user", "name", "max", "buffer", "size",
+        ])
+    }
+
+    #[test]
+    fn test_segment_known_words() {
+        let dictionary = dictionary();
+        assert_eq!(vec!["file", "system", "watcher"], dictionary.segment("filesystemwatcher"));
+        assert_eq!(vec!["user", "name"], dictionary.segment("username"));
+    }
+
+    #[test]
+    fn test_segment_case_insensitive() {
+        let dictionary = dictionary();
+        assert_eq!(vec!["MAX", "BUFFER", "SIZE"], dictionary.segment("MAXBUFFERSIZE"));
+    }
+
+    #[test]
+    fn test_segment_unknown_runs() {
+        let dictionary = dictionary();
+        assert_eq!(vec!["xyz", "file", "qq"], dictionary.segment("xyzfileqq"));
+        assert_eq!(vec!["unknown"], dictionary.segment("unknown"));
+    }
+
+    #[test]
+    fn test_segment_empty() {
+        assert!(dictionary().segment("").is_empty());
+        assert_eq!(vec!["abc"], Dictionary::default().segment("abc"));
+    }
+}
@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::fmt;
+
+/// Why a string is not in a particular case, and where.
+///
+/// ```
+/// use camel_kebab::{Case, CaseErrorKind, SnakeCase};
+///
+/// let error = SnakeCase::try_parse("max_Size").unwrap_err();
+/// assert_eq!(4, error.offset);
+/// assert_eq!('S', error.ch);
+/// assert_eq!(CaseErrorKind::Uppercase, error.kind);
+/// assert_eq!("uppercase letter 'S' at byte 4", error.to_string());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CaseError {
+    /// The byte offset of the character at fault
+    pub offset: usize,
+    /// The character at fault. For a delimiter of more than one character, this is its first
+    /// character.
+    pub ch: char,
+    pub kind: CaseErrorKind,
+}
+
+/// The reason for a `CaseError`. A delimiter at the start or end, or two delimiters in a row,
+/// would leave an empty word.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CaseErrorKind {
+    /// An uppercase letter where it is not allowed, such as in snake case
+    Uppercase,
+    /// A lowercase letter where it is not allowed, such as in screaming snake case
+    Lowercase,
+    /// A character that is neither a letter, a digit nor the delimiter of the case
+    NonAlphanumeric,
+    LeadingDelimiter,
+    TrailingDelimiter,
+    RepeatedDelimiter,
+}
+
+impl CaseError {
+    #[inline]
+    pub(crate) fn new(offset: usize, ch: char, kind: CaseErrorKind) -> CaseError {
+        CaseError { offset, ch, kind }
+    }
+}
+
+impl fmt::Display for CaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let reason = match self.kind {
+            CaseErrorKind::Uppercase => "uppercase letter",
+            CaseErrorKind::Lowercase => "lowercase letter",
+            CaseErrorKind::NonAlphanumeric => "non-alphanumeric character",
+            CaseErrorKind::LeadingDelimiter => "leading delimiter",
+            CaseErrorKind::TrailingDelimiter => "trailing delimiter",
+            CaseErrorKind::RepeatedDelimiter => "repeated delimiter",
+        };
+        write!(f, "{} {:?} at byte {}", reason, self.ch, self.offset)
+    }
+}
+
+impl Error for CaseError {}
+
+/// A conversion that would lose word boundaries, because the target case cannot represent the
+/// words of the source exactly. Reading `rendered` back in the target case gives different
+/// words, such as when `io_2_x` is converted to camel case as `io2X`, which is read back as
+/// `io2` and `X`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LossyConversion {
+    /// The source value, in the target case
+    pub rendered: String,
+    /// The words of the source value
+    pub words: Vec<String>,
+    /// The words found when `rendered` is read back in the target case
+    pub read_back: Vec<String>,
+}
+
+impl fmt::Display for LossyConversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{:?} is read back as the words {:?}, not {:?}",
+            self.rendered, self.read_back, self.words
+        )
+    }
+}
+
+impl Error for LossyConversion {}
+
+/// The error returned when parsing a `CaseKind` from a name that is not known
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseCaseKindError {
+    pub name: String,
+}
+
+impl fmt::Display for ParseCaseKindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "unknown case {:?}", self.name)
+    }
+}
+
+impl Error for ParseCaseKindError {}
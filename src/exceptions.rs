@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+/// Words with a preferred spelling, such as acronyms and brand names, for use when rendering a
+/// case. Words are looked up case-insensitively.
+///
+/// ```
+/// use camel_kebab::{Case, Exceptions, PascalCase, RenderOptions, SnakeCase};
+///
+/// let snake = SnakeCase::str_as_case_unchecked("xml_http_request");
+/// let pascal = PascalCase::from(snake);
+/// assert_eq!("XmlHttpRequest", pascal.to_string());
+///
+/// let go = Exceptions::go_initialisms();
+/// let options = RenderOptions::default().exceptions(&go);
+/// assert_eq!("XMLHTTPRequest", pascal.display_with(options).to_string());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Exceptions {
+    capitalized: HashMap<String, String>,
+    lowercase: HashMap<String, String>,
+}
+
+/// The initialisms that Go style keeps in a single case, from `golint`
+const GO_INITIALISMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IP",
+    "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS", "TTL",
+    "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP", "XSRF", "XSS",
+];
+
+impl Exceptions {
+    pub fn new() -> Exceptions {
+        Exceptions::default()
+    }
+
+    /// Go's standard initialisms, such as `HTTP`, `ID` and `URL`, which are written in uppercase
+    /// wherever the word would otherwise be capitalized.
+    pub fn go_initialisms() -> Exceptions {
+        let mut exceptions = Exceptions::new();
+        for initialism in GO_INITIALISMS {
+            exceptions.insert(initialism);
+        }
+        exceptions
+    }
+
+    /// Use `spelling` wherever the word would otherwise be capitalized, e.g. `GitHub` or `XML`.
+    pub fn insert(&mut self, spelling: &str) {
+        self.capitalized.insert(spelling.to_lowercase(), spelling.to_string());
+    }
+
+    /// Use `spelling` where the word begins camel case, and would otherwise be written in
+    /// lowercase, e.g. `iPhone`. Cases with only lowercase words, such as snake case, are not
+    /// affected.
+    pub fn insert_lowercase(&mut self, spelling: &str) {
+        self.lowercase.insert(spelling.to_lowercase(), spelling.to_string());
+    }
+
+    pub(crate) fn capitalized(&self, word: &str) -> Option<&str> {
+        lookup(&self.capitalized, word)
+    }
+
+    pub(crate) fn lowercase(&self, word: &str) -> Option<&str> {
+        lookup(&self.lowercase, word)
+    }
+}
+
+#[inline]
+fn lookup<'e>(map: &'e HashMap<String, String>, word: &str) -> Option<&'e str> {
+    if map.is_empty() {
+        None
+    } else {
+        map.get(&word.to_lowercase()).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_ignores_case() {
+        let mut exceptions = Exceptions::new();
+        exceptions.insert("GitHub");
+        assert_eq!(Some("GitHub"), exceptions.capitalized("github"));
+        assert_eq!(Some("GitHub"), exceptions.capitalized("GITHUB"));
+        assert_eq!(None, exceptions.lowercase("github"));
+        assert_eq!(None, exceptions.capitalized("git"));
+    }
+
+    #[test]
+    fn test_go_initialisms() {
+        let exceptions = Exceptions::go_initialisms();
+        assert_eq!(Some("HTTP"), exceptions.capitalized("Http"));
+        assert_eq!(Some("ID"), exceptions.capitalized("id"));
+        assert_eq!(None, exceptions.lowercase("id"));
+    }
+}
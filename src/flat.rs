@@ -0,0 +1,92 @@
+use crate::internals::{joined_words, split_words_on_digits, self, validate_word, write_delimited, CaseValue, Word};
+use crate::{Case, CaseError, Dictionary, ParseOptions, RenderOptions, WordCasing};
+use std::borrow::Borrow;
+use std::fmt;
+
+/// `flatcase`: lowercase words with no separator. Since there are no word boundaries to find, a
+/// `Dictionary` is needed to parse words from a string. Without one, the whole string is treated
+/// as a single word.
+#[derive(Debug, Clone)]
+pub struct FlatCase<'a>(pub(crate) CaseValue<'a>);
+
+impl<'a> FlatCase<'a> {
+    /// Parse `source`, using `dictionary` to decide where the words start and end.
+    pub fn str_as_case_with_dictionary(source: &'a str, dictionary: &Dictionary) -> Option<Self> {
+        if Self::str_is_case(source) {
+            let words = dictionary.segment(source).into_iter().map(Word::lower_case).collect();
+            Some(FlatCase(CaseValue::Words(words)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Case<'a> for FlatCase<'a> {
+    #[inline]
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_word(source, 0, WordCasing::Lower)
+    }
+
+    #[inline]
+    fn str_as_case_unchecked(source: &'a str) -> Self {
+        FlatCase(CaseValue::Joined(source.into()))
+    }
+}
+
+impl<'a> internals::Case<'a> for FlatCase<'a> {
+    #[inline]
+    fn from_cased_words(words: Vec<Word<'a>>) -> Self {
+        FlatCase(CaseValue::Words(words))
+    }
+
+    #[inline]
+    fn to_cased_words(self) -> Vec<Word<'a>> {
+        match self.0 {
+            CaseValue::Words(words) => words,
+            CaseValue::Joined(string) => joined_words::<Self>(string),
+        }
+    }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        split_words_on_digits(source, options).map(Word::lower_case)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        write_delimited(words, f, "", WordCasing::Lower, options)
+    }
+}
+
+impl<'a> fmt::Display for FlatCase<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match &self.0 {
+            CaseValue::Joined(string) => string.fmt(f),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
+        }
+    }
+}
+
+impl_str_ext! {
+    trait: FlatCaseExt,
+    case: FlatCase,
+    as: as_flat_case,
+    as_unchecked: as_flat_case_unchecked,
+    is: is_flat_case,
+    to: to_flat_case
+}
+
+impl_eq!(FlatCase);
+
+impl_into_owned!(FlatCase);
@@ -0,0 +1,155 @@
+use crate::internals::{
+    self, cased_word, joined_words, split_words_on_digits, validate_delimited_with, validate_word, CaseValue, Word,
+};
+use crate::{Case, CaseError, CaseErrorKind, ParseOptions, RenderOptions, WordCasing};
+use std::borrow::Borrow;
+use std::fmt;
+
+/// Words whose canonical spelling in an HTTP header name is not simply capitalized. The first
+/// element of each pair is the lowercase form, used for lookups.
+const OVERRIDES: &[(&str, &str)] = &[
+    ("csp", "CSP"),
+    ("dnt", "DNT"),
+    ("etag", "ETag"),
+    ("md5", "MD5"),
+    ("te", "TE"),
+    ("ua", "UA"),
+    ("websocket", "WebSocket"),
+    ("www", "WWW"),
+    ("xss", "XSS"),
+];
+
+fn canonical_override(word: &str) -> Option<&'static str> {
+    OVERRIDES
+        .iter()
+        .find(|(lower, _)| lower.eq_ignore_ascii_case(word))
+        .map(|&(_, canonical)| canonical)
+}
+
+/// Check a single word, found at `offset`, against its canonical spelling
+fn validate_header_word(word: &str, offset: usize) -> Result<(), CaseError> {
+    match canonical_override(word) {
+        Some(canonical) => {
+            match word.char_indices().zip(canonical.chars()).find(|&((_, ch), expected)| ch != expected) {
+                Some(((i, ch), _)) => {
+                    let kind = if ch.is_uppercase() {
+                        CaseErrorKind::Uppercase
+                    } else {
+                        CaseErrorKind::Lowercase
+                    };
+                    Err(CaseError::new(offset + i, ch, kind))
+                }
+                None => Ok(()),
+            }
+        }
+        None => validate_word(word, offset, WordCasing::Capitalized),
+    }
+}
+
+/// Train-Case with the spelling exceptions of canonical HTTP header names, such as
+/// `WWW-Authenticate`, `ETag` and `X-XSS-Protection`.
+#[derive(Debug, Clone)]
+pub struct HttpHeaderCase<'a>(pub(crate) CaseValue<'a>);
+
+impl<'a> HttpHeaderCase<'a> {
+    /// Canonicalize a `-` delimited header name, regardless of the case of its words.
+    pub fn canonicalize(name: &'a str) -> Self {
+        HttpHeaderCase(CaseValue::Words(name.split('-').map(Word::mixed_case).collect()))
+    }
+}
+
+impl<'a> Case<'a> for HttpHeaderCase<'a> {
+    #[inline]
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_delimited_with(source, "-", validate_header_word)
+    }
+
+    #[inline]
+    fn str_as_case_unchecked(source: &'a str) -> Self {
+        HttpHeaderCase(CaseValue::Joined(source.into()))
+    }
+}
+
+impl<'a> internals::Case<'a> for HttpHeaderCase<'a> {
+    #[inline]
+    fn from_cased_words(words: Vec<Word<'a>>) -> Self {
+        HttpHeaderCase(CaseValue::Words(words))
+    }
+
+    #[inline]
+    fn to_cased_words(self) -> Vec<Word<'a>> {
+        match self.0 {
+            CaseValue::Words(words) => words,
+            CaseValue::Joined(string) => joined_words::<Self>(string),
+        }
+    }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        source.split('-').flat_map(move |word| {
+            // Words with a special spelling, like `MD5`, are never split
+            let options = if canonical_override(word).is_some() {
+                ParseOptions::default()
+            } else {
+                options
+            };
+            split_words_on_digits(word, options).map(|word| {
+                if canonical_override(word).is_none() {
+                    cased_word(word)
+                } else if word.chars().all(|ch| !ch.is_lowercase()) {
+                    Word::acronym(word)
+                } else {
+                    Word::mixed_case(word)
+                }
+            })
+        })
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        for (i, word) in words.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str("-")?;
+            }
+            let word = word.borrow();
+            match canonical_override(word.as_str()) {
+                Some(canonical) => f.write_str(canonical)?,
+                None => word.write_capitalized_with(f, options)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for HttpHeaderCase<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match &self.0 {
+            CaseValue::Joined(string) => string.fmt(f),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
+        }
+    }
+}
+
+impl_str_ext! {
+    trait: HttpHeaderCaseExt,
+    case: HttpHeaderCase,
+    as: as_http_header_case,
+    as_unchecked: as_http_header_case_unchecked,
+    is: is_http_header_case,
+    to: to_http_header_case
+}
+
+impl_eq!(HttpHeaderCase);
+
+impl_into_owned!(HttpHeaderCase);
@@ -1,102 +1,295 @@
+use crate::locale;
+use crate::{AcronymPolicy, CaseError, CaseErrorKind, DigitPolicy, ParseOptions, RenderOptions, ScriptPolicy, WordCasing};
+use std::borrow::{Borrow, Cow};
 use std::fmt;
 use std::iter::Peekable;
+use unicode_script::{Script, UnicodeScript};
+use unicode_segmentation::UnicodeSegmentation;
 
-
-#[derive(Debug, Copy, Clone)]
+/// A word of a case value, as given by `Case::words`. A word knows how it is cased, and can be
+/// written in any casing.
+///
+/// ```
+/// use camel_kebab::{Case, PascalCase, WordKind};
+///
+/// let pascal = PascalCase::str_as_case("XMLHttpRequest").unwrap();
+/// let kinds: Vec<_> = pascal.words().map(|word| word.kind()).collect();
+/// assert_eq!(vec![WordKind::Acronym, WordKind::Capitalized, WordKind::Capitalized], kinds);
+///
+/// let mut lower = String::new();
+/// for word in pascal.words() {
+///     word.write_lowercase(&mut lower).unwrap();
+///     lower.push(' ');
+/// }
+/// assert_eq!("xml http request ", lower);
+/// ```
+#[derive(Debug, Clone)]
 pub struct Word<'a>(WordInner<'a>);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 enum WordInner<'a> {
-    LowerCase(&'a str),
-    MixedCase(&'a str),
-    Capitalized(&'a str),
-    UpperCase(&'a str),
+    LowerCase(Cow<'a, str>),
+    MixedCase(Cow<'a, str>),
+    Capitalized(Cow<'a, str>),
+    UpperCase(Cow<'a, str>),
+    /// An uppercase word among words that are otherwise capitalized, like `XML` in `XMLHttp`
+    Acronym(Cow<'a, str>),
+}
+
+/// How a `Word` is cased
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WordKind {
+    /// No uppercase letters, like `max`. This includes words in scripts without case, like `東京`.
+    Lower,
+    /// No lowercase letters, like `MAX` in `MAX_SIZE`
+    Upper,
+    /// An uppercase first letter and no other uppercase letters, like `Max`
+    Capitalized,
+    /// Any other mixture of uppercase and lowercase letters, like `iPhone`
+    Mixed,
+    /// Digits only, like `2` in `io_2_x`
+    Numeric,
+    /// An uppercase word in a case whose words are otherwise capitalized, like `XML` in
+    /// `XMLHttpRequest` or `WWW` in `WWW-Authenticate`
+    Acronym,
 }
 
 #[derive(Debug, Clone)]
 pub enum CaseValue<'a> {
     Words(Vec<Word<'a>>),
-    Joined(&'a str),
+    Joined(Cow<'a, str>),
+}
+
+impl<'a> CaseValue<'a> {
+    /// Take ownership of the text, so that it no longer borrows from its source. Words are kept
+    /// as they are, so the word boundaries are not lost.
+    pub(crate) fn into_owned(self) -> CaseValue<'static> {
+        match self {
+            CaseValue::Joined(string) => CaseValue::Joined(Cow::Owned(string.into_owned())),
+            CaseValue::Words(words) => {
+                CaseValue::Words(words.into_iter().map(Word::into_owned).collect())
+            }
+        }
+    }
 }
 
 impl<'a> Word<'a> {
     /// It is up to the caller to verify that the input is in the correct case
-    pub fn lower_case(input: &'a str) -> Word<'a> {
-        let word = Word(WordInner::LowerCase(input));
+    pub(crate) fn lower_case(input: &'a str) -> Word<'a> {
+        let word = Word(WordInner::LowerCase(Cow::Borrowed(input)));
+        debug_assert!(word.is_valid());
+        word
+    }
+
+    /// It is up to the caller to verify that the input is in the correct case
+    pub(crate) fn upper_case(input: &'a str) -> Word<'a> {
+        let word = Word(WordInner::UpperCase(Cow::Borrowed(input)));
         debug_assert!(word.is_valid());
         word
     }
 
     /// It is up to the caller to verify that the input is in the correct case
-    pub fn upper_case(input: &'a str) -> Word<'a> {
-        let word = Word(WordInner::UpperCase(input));
+    pub(crate) fn mixed_case(input: &'a str) -> Word<'a> {
+        let word = Word(WordInner::MixedCase(Cow::Borrowed(input)));
         debug_assert!(word.is_valid());
         word
     }
 
     /// It is up to the caller to verify that the input is in the correct case
-    pub fn mixed_case(input: &'a str) -> Word<'a> {
-        let word = Word(WordInner::MixedCase(input));
+    pub(crate) fn capitalized(input: &'a str) -> Word<'a> {
+        let word = Word(WordInner::Capitalized(Cow::Borrowed(input)));
         debug_assert!(word.is_valid());
         word
     }
 
     /// It is up to the caller to verify that the input is in the correct case
-    pub fn capitalized(input: &'a str) -> Word<'a> {
-        let word = Word(WordInner::Capitalized(input));
+    pub(crate) fn acronym(input: &'a str) -> Word<'a> {
+        let word = Word(WordInner::Acronym(Cow::Borrowed(input)));
         debug_assert!(word.is_valid());
         word
     }
 
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            WordInner::LowerCase(word)
+            | WordInner::MixedCase(word)
+            | WordInner::Capitalized(word)
+            | WordInner::UpperCase(word)
+            | WordInner::Acronym(word) => word,
+        }
+    }
+
+    /// How this word is cased
+    pub fn kind(&self) -> WordKind {
+        let word = self.as_str();
+        if !word.is_empty() && word.chars().all(char::is_numeric) {
+            return WordKind::Numeric;
+        }
+        match &self.0 {
+            WordInner::LowerCase(_) => WordKind::Lower,
+            WordInner::Capitalized(_) => WordKind::Capitalized,
+            WordInner::UpperCase(_) => WordKind::Upper,
+            WordInner::Acronym(_) => WordKind::Acronym,
+            WordInner::MixedCase(word) => match cased_word(word).0 {
+                WordInner::LowerCase(_) => WordKind::Lower,
+                WordInner::Capitalized(_) => WordKind::Capitalized,
+                WordInner::UpperCase(_) => WordKind::Upper,
+                _ => WordKind::Mixed,
+            },
+        }
+    }
+
+    /// The same word, borrowing its text from this one
+    #[inline]
+    pub(crate) fn reborrow(&self) -> Word<'_> {
+        let word = Cow::Borrowed(self.as_str());
+        Word(match &self.0 {
+            WordInner::LowerCase(_) => WordInner::LowerCase(word),
+            WordInner::MixedCase(_) => WordInner::MixedCase(word),
+            WordInner::Capitalized(_) => WordInner::Capitalized(word),
+            WordInner::UpperCase(_) => WordInner::UpperCase(word),
+            WordInner::Acronym(_) => WordInner::Acronym(word),
+        })
+    }
+
+    /// A copy of this word that does not borrow from its source
+    pub fn into_owned(self) -> Word<'static> {
+        let inner = match self.0 {
+            WordInner::LowerCase(word) => WordInner::LowerCase(Cow::Owned(word.into_owned())),
+            WordInner::MixedCase(word) => WordInner::MixedCase(Cow::Owned(word.into_owned())),
+            WordInner::Capitalized(word) => WordInner::Capitalized(Cow::Owned(word.into_owned())),
+            WordInner::UpperCase(word) => WordInner::UpperCase(Cow::Owned(word.into_owned())),
+            WordInner::Acronym(word) => WordInner::Acronym(Cow::Owned(word.into_owned())),
+        };
+        Word(inner)
+    }
+
+    #[inline]
     pub fn write_lowercase<W: fmt::Write>(&self, f: &mut W) -> Result<(), fmt::Error> {
+        self.write_lowercase_with(f, &RenderOptions::default())
+    }
+
+    pub fn write_lowercase_with<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        options: &RenderOptions,
+    ) -> Result<(), fmt::Error> {
         debug_assert!(self.is_valid());
+        if options.ascii {
+            let ascii = transliterate(self.as_str());
+            let options = RenderOptions { ascii: false, ..*options };
+            return cased_word(&ascii).write_lowercase_with(f, &options);
+        }
+        if let Some(locale) = options.locale {
+            return locale::write_lower_from(f, self.as_str(), 0, locale);
+        }
         match &self.0 {
             WordInner::LowerCase(word) => f.write_str(word),
-            WordInner::MixedCase(word) | WordInner::UpperCase(word) => write!(f, "{}", word.to_lowercase()),
+            WordInner::MixedCase(word) | WordInner::UpperCase(word) | WordInner::Acronym(word) => {
+                write_lower_str(f, word)
+            }
             WordInner::Capitalized(word) => {
-                let mut chars = word.chars();
-                if let Some(first) = chars.next() {
-                    write!(f, "{}", first.to_lowercase())?;
-                    let rest = unsafe { word.get_unchecked(first.len_utf8()..) };
-                    f.write_str(&rest)?;
-                }
-                Ok(())
+                let (first, rest) = split_first_grapheme(word);
+                write_lower_str(f, first)?;
+                f.write_str(rest)
             }
         }
     }
 
+    #[inline]
+    pub fn write_uppercase<W: fmt::Write>(&self, f: &mut W) -> Result<(), fmt::Error> {
+        self.write_uppercase_with(f, &RenderOptions::default())
+    }
+
+    pub fn write_uppercase_with<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        options: &RenderOptions,
+    ) -> Result<(), fmt::Error> {
+        debug_assert!(self.is_valid());
+        if options.ascii {
+            let ascii = transliterate(self.as_str());
+            let options = RenderOptions { ascii: false, ..*options };
+            return cased_word(&ascii).write_uppercase_with(f, &options);
+        }
+        if let Some(locale) = options.locale {
+            return locale::write_upper(f, self.as_str(), locale);
+        }
+        match &self.0 {
+            WordInner::UpperCase(word) | WordInner::Acronym(word) => f.write_str(word),
+            WordInner::LowerCase(word)
+            | WordInner::MixedCase(word)
+            | WordInner::Capitalized(word) => write_upper_str(f, word),
+        }
+    }
+
+    #[inline]
     pub fn write_capitalized<W: fmt::Write>(&self, f: &mut W) -> Result<(), fmt::Error> {
+        self.write_capitalized_with(f, &RenderOptions::default())
+    }
+
+    pub fn write_capitalized_with<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        options: &RenderOptions,
+    ) -> Result<(), fmt::Error> {
         debug_assert!(self.is_valid());
+        if options.ascii {
+            let ascii = transliterate(self.as_str());
+            let options = RenderOptions { ascii: false, ..*options };
+            return cased_word(&ascii).write_capitalized_with(f, &options);
+        }
+        if let Some(spelling) = options.exceptions.and_then(|e| e.capitalized(self.as_str())) {
+            return f.write_str(spelling);
+        }
+        if let Some(locale) = options.locale {
+            return locale::write_title(f, self.as_str(), locale);
+        }
         match &self.0 {
             WordInner::LowerCase(word) => {
-                let mut chars = word.chars();
-                if let Some(first) = chars.next() {
-                    write!(f, "{}", first.to_uppercase())?;
-                    let rest = unsafe { word.get_unchecked(first.len_utf8()..) };
-                    f.write_str(&rest)?;
-                }
-                Ok(())
+                let (first, rest) = split_first_grapheme(word);
+                write_titlecase_grapheme(f, first)?;
+                f.write_str(rest)
             }
-            WordInner::MixedCase(word) | WordInner::UpperCase(word) => {
-                let mut chars = word.chars();
-                if let Some(first) = chars.next() {
-                    write!(f, "{}", first.to_uppercase())?;
-                    let rest = unsafe { word.get_unchecked(first.len_utf8()..) };
-                    f.write_str(&rest.to_lowercase())?;
+            WordInner::MixedCase(word) | WordInner::UpperCase(word) | WordInner::Acronym(word) => {
+                let (first, rest) = split_first_grapheme(word);
+                write_titlecase_grapheme(f, first)?;
+                if word.contains('\u{3A3}') {
+                    // Final sigma depends on the letters before it, so lowercase the whole word
+                    let lower = word.to_lowercase();
+                    f.write_str(&lower[first.to_lowercase().len()..])
+                } else {
+                    write_lower_str(f, rest)
                 }
-                Ok(())
             }
             WordInner::Capitalized(word) => f.write_str(word),
         }
     }
 
+    #[inline]
+    pub fn write_with_casing<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        casing: WordCasing,
+        options: &RenderOptions,
+    ) -> Result<(), fmt::Error> {
+        match casing {
+            WordCasing::Lower => self.write_lowercase_with(f, options),
+            WordCasing::Upper => self.write_uppercase_with(f, options),
+            WordCasing::Capitalized => self.write_capitalized_with(f, options),
+        }
+    }
+
     /// It is assumed that a Word is constructed with a string already in the correct case.
     /// However, this method is useful in debugging.
-    pub fn is_valid(&self) -> bool {
+    pub(crate) fn is_valid(&self) -> bool {
         match &self.0 {
             WordInner::LowerCase(word) => word.chars().all(|c| !c.is_uppercase()),
             WordInner::MixedCase(_word) => true,
-            WordInner::UpperCase(word) => word.chars().all(|c| !c.is_lowercase()),
+            WordInner::UpperCase(word) | WordInner::Acronym(word) => {
+                word.chars().all(|c| !c.is_lowercase())
+            }
             WordInner::Capitalized(word) => {
                 let mut chars = word.chars();
                 if let Some(first) = chars.next() {
@@ -113,10 +306,131 @@ impl<'a> Word<'a> {
     }
 }
 
+impl<'a> fmt::Display for Word<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Split a word after its first extended grapheme cluster, so that a letter is always cased
+/// together with any combining marks that follow it
+#[inline]
+pub(crate) fn split_first_grapheme(word: &str) -> (&str, &str) {
+    let len = word.graphemes(true).next().map_or(0, str::len);
+    word.split_at(len)
+}
+
+/// Write `text` in lowercase, as by `str::to_lowercase`. Only text with a capital sigma, whose
+/// lowercase form depends on its position, is lowercased in a new `String`.
+#[inline]
+fn write_lower_str<W: fmt::Write>(f: &mut W, text: &str) -> Result<(), fmt::Error> {
+    if text.contains('\u{3A3}') {
+        return f.write_str(&text.to_lowercase());
+    }
+    text.chars().flat_map(char::to_lowercase).try_for_each(|ch| f.write_char(ch))
+}
+
+/// Write `text` in uppercase, as by `str::to_uppercase`
+#[inline]
+fn write_upper_str<W: fmt::Write>(f: &mut W, text: &str) -> Result<(), fmt::Error> {
+    text.chars().flat_map(char::to_uppercase).try_for_each(|ch| f.write_char(ch))
+}
+
+/// Transliterate a word to ASCII letters and digits
+pub(crate) fn transliterate(word: &str) -> String {
+    let mut ascii = deunicode::deunicode_with_tofu(word, "");
+    ascii.retain(|ch| ch.is_ascii_alphanumeric());
+    ascii
+}
+
+/// Write a grapheme cluster at the start of a capitalized word. Only its first char has case.
+#[inline]
+fn write_titlecase_grapheme<W: fmt::Write>(f: &mut W, grapheme: &str) -> Result<(), fmt::Error> {
+    let mut chars = grapheme.chars();
+    if let Some(first) = chars.next() {
+        locale::write_titlecase(f, first)?;
+        f.write_str(chars.as_str())?;
+    }
+    Ok(())
+}
+
+/// The `Word` for some text whose case is not known in advance
+pub(crate) fn cased_word(word: &str) -> Word<'_> {
+    let mut chars = word.chars();
+    let first_upper = chars.next().is_some_and(char::is_uppercase);
+    if word.chars().all(|ch| !ch.is_uppercase()) {
+        Word::lower_case(word)
+    } else if word.chars().all(|ch| !ch.is_lowercase()) {
+        Word::upper_case(word)
+    } else if first_upper && chars.all(|ch| !ch.is_uppercase()) {
+        Word::capitalized(word)
+    } else {
+        Word::mixed_case(word)
+    }
+}
+
+/// Whether two words have the same text, ignoring case
+pub(crate) fn words_eq(a: &Word, b: &Word) -> bool {
+    a.as_str()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.as_str().chars().flat_map(char::to_lowercase))
+}
+
 pub trait Case<'a>: Sized {
     fn from_cased_words(words: Vec<Word<'a>>) -> Self;
 
     fn to_cased_words(self) -> Vec<Word<'a>>;
+
+    /// Split a string, which is assumed to be in this case, into words
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>>;
+
+    fn case_value(&self) -> &CaseValue<'a>;
+
+    /// Write words in this case
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>;
+}
+
+/// The words of a string in case `C`
+pub(crate) fn joined_words<'a, C: Case<'a>>(joined: Cow<'a, str>) -> Vec<Word<'a>> {
+    match joined {
+        Cow::Borrowed(string) => C::split_joined(string, ParseOptions::default()).collect(),
+        Cow::Owned(string) => C::split_joined(&string, ParseOptions::default())
+            .map(Word::into_owned)
+            .collect(),
+    }
+}
+
+/// Write a value in case `C` in case `T`, straight from the words of its source
+pub(crate) fn write_converted<'a, C, T, W>(case: &C, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+where
+    C: Case<'a>,
+    T: Case<'a>,
+    W: fmt::Write,
+{
+    match case.case_value() {
+        CaseValue::Joined(string) => T::write_words(C::split_joined(string, ParseOptions::default()), f, options),
+        CaseValue::Words(words) => T::write_words(words, f, options),
+    }
+}
+
+/// Write a case value, re-rendering it from its words if the options require it
+pub(crate) fn write_case<'a, C, W>(case: &C, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+where
+    C: Case<'a>,
+    W: fmt::Write,
+{
+    match case.case_value() {
+        CaseValue::Joined(string) if options.is_plain() => f.write_str(string),
+        CaseValue::Joined(string) => {
+            C::write_words(C::split_joined(string, ParseOptions::default()), f, options)
+        }
+        CaseValue::Words(words) => C::write_words(words, f, options),
+    }
 }
 
 #[inline]
@@ -124,30 +438,107 @@ pub(crate) fn write_lower_delimited<'w, W, I>(
     words: I,
     buf: &mut W,
     sep: char,
+    options: &RenderOptions,
+) -> Result<(), fmt::Error>
+where
+    W: fmt::Write,
+    I: IntoIterator,
+    I::Item: Borrow<Word<'w>>,
+{
+    let mut iter = words.into_iter();
+    if let Some(first_word) = iter.next() {
+        first_word.borrow().write_lowercase_with(buf, options)?;
+        for word in iter {
+            buf.write_char(sep)?;
+            word.borrow().write_lowercase_with(buf, options)?;
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn write_upper_delimited<'w, W, I>(
+    words: I,
+    buf: &mut W,
+    sep: char,
+    options: &RenderOptions,
+) -> Result<(), fmt::Error>
+where
+    W: fmt::Write,
+    I: IntoIterator,
+    I::Item: Borrow<Word<'w>>,
+{
+    let mut iter = words.into_iter();
+    if let Some(first_word) = iter.next() {
+        first_word.borrow().write_uppercase_with(buf, options)?;
+        for word in iter {
+            buf.write_char(sep)?;
+            word.borrow().write_uppercase_with(buf, options)?;
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn write_capitalized_delimited<'w, W, I>(
+    words: I,
+    buf: &mut W,
+    sep: char,
+    options: &RenderOptions,
 ) -> Result<(), fmt::Error>
 where
     W: fmt::Write,
-    I: IntoIterator<Item = &'w Word<'w>>,
+    I: IntoIterator,
+    I::Item: Borrow<Word<'w>>,
 {
     let mut iter = words.into_iter();
     if let Some(first_word) = iter.next() {
-        first_word.write_lowercase(buf)?;
+        first_word.borrow().write_capitalized_with(buf, options)?;
         for word in iter {
             buf.write_char(sep)?;
-            word.write_lowercase(buf)?;
+            word.borrow().write_capitalized_with(buf, options)?;
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn write_delimited<'w, W, I>(
+    words: I,
+    buf: &mut W,
+    sep: &str,
+    casing: WordCasing,
+    options: &RenderOptions,
+) -> Result<(), fmt::Error>
+where
+    W: fmt::Write,
+    I: IntoIterator,
+    I::Item: Borrow<Word<'w>>,
+{
+    let mut iter = words.into_iter();
+    if let Some(first_word) = iter.next() {
+        first_word.borrow().write_with_casing(buf, casing, options)?;
+        for word in iter {
+            buf.write_str(sep)?;
+            word.borrow().write_with_casing(buf, casing, options)?;
         }
     }
     Ok(())
 }
 
 #[inline]
-pub(crate) fn write_pascal_case<'w, W, I>(words: I, buf: &mut W) -> Result<(), fmt::Error>
+pub(crate) fn write_pascal_case<'w, W, I>(
+    words: I,
+    buf: &mut W,
+    options: &RenderOptions,
+) -> Result<(), fmt::Error>
 where
     W: fmt::Write,
-    I: IntoIterator<Item = &'w Word<'w>>,
+    I: IntoIterator,
+    I::Item: Borrow<Word<'w>>,
 {
     for word in words {
-        word.write_capitalized(buf)?;
+        word.borrow().write_capitalized_with(buf, options)?;
     }
     Ok(())
 }
@@ -156,18 +547,92 @@ struct UpperCaseSplitIter<'a, C: Iterator> {
     source: &'a str,
     word_start: usize,
     chars: Peekable<C>,
+    /// Whether uppercase letters start new words. If not, only digits can split a word.
+    humps: bool,
+    acronyms: AcronymPolicy,
+    digits: DigitPolicy,
+    scripts: ScriptPolicy,
+    /// The script of the current word so far, ignoring characters that are shared by scripts
+    script: Option<Script>,
+}
+
+impl<'a, C: Iterator<Item = (usize, char)>> UpperCaseSplitIter<'a, C> {
+    /// Whether a new word starts with `ch`, at index `n`, given the character before it. Both are
+    /// the first characters of their grapheme clusters.
+    fn is_boundary(&self, prev: char, n: usize, ch: char) -> bool {
+        let digit_boundary = match self.digits {
+            DigitPolicy::Attach => false,
+            DigitPolicy::StartWord => ch.is_numeric() && !prev.is_numeric(),
+            DigitPolicy::Standalone => ch.is_numeric() != prev.is_numeric(),
+        };
+        if digit_boundary {
+            return true;
+        }
+        if !self.humps || !ch.is_uppercase() {
+            return false;
+        }
+        match self.acronyms {
+            AcronymPolicy::SplitLetters => true,
+            AcronymPolicy::Group => {
+                // A run of capitals is an acronym, except that its last capital begins the
+                // next word if a lowercase letter follows it, e.g. `HTTPServer`
+                !prev.is_uppercase() || self.source[n..]
+                    .graphemes(true)
+                    .nth(1)
+                    .and_then(|next| next.chars().next())
+                    .is_some_and(char::is_lowercase)
+            }
+        }
+    }
+}
+
+impl<'a, C: Iterator<Item = (usize, char)>> UpperCaseSplitIter<'a, C> {
+    fn new(source: &'a str, chars: C, humps: bool, options: ParseOptions) -> Self {
+        UpperCaseSplitIter {
+            source,
+            word_start: 0,
+            chars: chars.peekable(),
+            humps,
+            acronyms: options.acronyms,
+            digits: options.digits,
+            scripts: options.scripts,
+            script: None,
+        }
+    }
+
+    /// Whether `ch` is in a different script to the word before it
+    #[inline]
+    fn is_script_boundary(&self, ch: char) -> bool {
+        self.scripts == ScriptPolicy::Split
+            && self.script.is_some()
+            && word_script(ch).is_some_and(|script| Some(script) != self.script)
+    }
+}
+
+/// The script of a character for the purpose of finding word boundaries, or `None` if the
+/// character is used with more than one script
+#[inline]
+fn word_script(ch: char) -> Option<Script> {
+    match ch.script() {
+        Script::Common | Script::Inherited | Script::Unknown => None,
+        Script::Hiragana | Script::Katakana => Some(Script::Han),
+        script => Some(script),
+    }
 }
 
 impl<'a, C: Iterator<Item = (usize, char)>> Iterator for UpperCaseSplitIter<'a, C> {
     type Item = &'a str;
     fn next(&mut self) -> Option<Self::Item> {
-        while self.chars.next().is_some() {
-            match self.chars.peek() {
+        while let Some((_, prev)) = self.chars.next() {
+            if self.scripts == ScriptPolicy::Split {
+                self.script = word_script(prev).or(self.script);
+            }
+            match self.chars.peek().copied() {
                 None => {
                     let word = unsafe { self.source.get_unchecked(self.word_start..) };
                     return Some(word);
                 }
-                Some(&(n, c)) if c.is_uppercase() => {
+                Some((n, c)) if self.is_boundary(prev, n, c) || self.is_script_boundary(c) => {
                     let word = unsafe { self.source.get_unchecked(self.word_start..n) };
                     self.word_start = n;
                     return Some(word);
@@ -180,36 +645,130 @@ impl<'a, C: Iterator<Item = (usize, char)>> Iterator for UpperCaseSplitIter<'a,
 }
 
 #[inline]
-pub(crate) fn split_words_on_uppercase(source: &str) -> impl Iterator<Item = &str> {
-    UpperCaseSplitIter {
-        source,
-        chars: source.char_indices().peekable(),
-        word_start: 0,
+pub(crate) fn split_words_on_uppercase(
+    source: &str,
+    options: ParseOptions,
+) -> impl Iterator<Item = &str> {
+    UpperCaseSplitIter::new(source, grapheme_starts(source), true, options)
+}
+
+/// The index and first character of each extended grapheme cluster. Words are only ever split
+/// at the start of a cluster.
+#[inline]
+fn grapheme_starts(source: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    source
+        .grapheme_indices(true)
+        .filter_map(|(i, grapheme)| grapheme.chars().next().map(|ch| (i, ch)))
+}
+
+/// Split a single word where the `DigitPolicy` requires it
+#[inline]
+pub(crate) fn split_words_on_digits(
+    source: &str,
+    options: ParseOptions,
+) -> impl Iterator<Item = &str> {
+    UpperCaseSplitIter::new(source, grapheme_starts(source), false, options)
+}
+
+/// Split on a delimiter, and then where the `DigitPolicy` requires it
+#[inline]
+pub(crate) fn split_delimited<'s>(
+    source: &'s str,
+    delim: &'s str,
+    options: ParseOptions,
+) -> impl Iterator<Item = &'s str> {
+    source
+        .split(delim)
+        .flat_map(move |word| split_words_on_digits(word, options))
+}
+
+/// The `Word` for a word split from camel or Pascal case, which begins with a capital unless it
+/// is the first word
+#[inline]
+pub(crate) fn hump_word(word: &str) -> Word<'_> {
+    if word.graphemes(true).nth(1).is_some() && word.chars().all(|ch| !ch.is_lowercase()) {
+        Word::acronym(word)
+    } else if word.chars().next().is_some_and(char::is_uppercase) {
+        Word::capitalized(word)
+    } else {
+        Word::lower_case(word)
     }
 }
 
-pub(crate) fn is_lower_case_delimited(source: &str, delim: char) -> bool {
-    let mut delim_allowed = false;
-    for ch in source.chars() {
-        if ch == delim {
-            if delim_allowed {
-                delim_allowed = false;
+/// Check that every word of `source` is cased as `casing` and that the words are separated by
+/// single delimiters
+pub(crate) fn validate_delimited(source: &str, delim: &str, casing: WordCasing) -> Result<(), CaseError> {
+    // Note: is_uppercase() is not equivalient to !is_lowercase(), which would return false for
+    // writing systems that do not have a notion of case (e.g. Kanji)
+    validate_delimited_with(source, delim, |word, offset| validate_word(word, offset, casing))
+}
+
+/// Check that `source` has no empty words between its delimiters, and that each word passes
+/// `validate`, which is given the word and its offset in `source`
+pub(crate) fn validate_delimited_with<F>(source: &str, delim: &str, validate: F) -> Result<(), CaseError>
+where
+    F: Fn(&str, usize) -> Result<(), CaseError>,
+{
+    if source.is_empty() {
+        return Ok(());
+    }
+    let delim_char = delim.chars().next().expect("delimiter must not be empty");
+    let mut offset = 0;
+    for word in source.split(delim) {
+        if word.is_empty() {
+            let (offset, kind) = if offset == 0 {
+                (0, CaseErrorKind::LeadingDelimiter)
+            } else if offset == source.len() {
+                (offset - delim.len(), CaseErrorKind::TrailingDelimiter)
             } else {
-                return false;   
-            }
-        // Note: is_uppercase() is not equivalient to !is_lowercase(), which would return false for
-        // writing systems that do not have a notion of case (e.g. Kanji)
-        } else if !ch.is_alphanumeric() || ch.is_uppercase() {
-            return false;
-        } else {
-            delim_allowed = true;
+                (offset, CaseErrorKind::RepeatedDelimiter)
+            };
+            return Err(CaseError::new(offset, delim_char, kind));
         }
+        validate(word, offset)?;
+        offset += word.len() + delim.len();
     }
+    Ok(())
+}
 
-    if let Some(ch) = source.chars().next_back() {
-        ch != delim
-    } else {
-        true
+/// Check that a single word, found at `offset`, is alphanumeric and cased as `casing`
+pub(crate) fn validate_word(word: &str, offset: usize, casing: WordCasing) -> Result<(), CaseError> {
+    validate_alphanumeric(word, offset)?;
+    for (i, ch) in word.char_indices() {
+        let kind = match casing {
+            WordCasing::Lower if ch.is_uppercase() => CaseErrorKind::Uppercase,
+            WordCasing::Upper if ch.is_lowercase() => CaseErrorKind::Lowercase,
+            WordCasing::Capitalized if i == 0 && ch.is_lowercase() => CaseErrorKind::Lowercase,
+            WordCasing::Capitalized if i > 0 && ch.is_uppercase() => CaseErrorKind::Uppercase,
+            _ => continue,
+        };
+        return Err(CaseError::new(offset + i, ch, kind));
+    }
+    Ok(())
+}
+
+/// Check camel or Pascal case: alphanumeric, and not beginning with an uppercase letter or, if
+/// `capitalized`, a lowercase letter. Like the other cases, a string may begin with a digit or a
+/// letter that has no case.
+pub(crate) fn validate_humps(source: &str, capitalized: bool) -> Result<(), CaseError> {
+    validate_alphanumeric(source, 0)?;
+    match source.chars().next() {
+        Some(first) if capitalized && first.is_lowercase() => {
+            Err(CaseError::new(0, first, CaseErrorKind::Lowercase))
+        }
+        Some(first) if !capitalized && first.is_uppercase() => {
+            Err(CaseError::new(0, first, CaseErrorKind::Uppercase))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that every grapheme cluster of `word`, found at `offset`, begins with a letter or
+/// digit. Combining marks are allowed after it, since they are never split from it.
+fn validate_alphanumeric(word: &str, offset: usize) -> Result<(), CaseError> {
+    match grapheme_starts(word).find(|(_, ch)| !ch.is_alphanumeric()) {
+        Some((i, ch)) => Err(CaseError::new(offset + i, ch, CaseErrorKind::NonAlphanumeric)),
+        None => Ok(()),
     }
 }
 
@@ -220,8 +779,9 @@ struct CompareBuf<'a> {
 
 impl<'a> std::fmt::Write for CompareBuf<'a> {
     fn write_str(&mut self, s: &str) -> Result<(), std::fmt::Error> {
-        if &self.expected.as_bytes()[self.position..s.len()] == s.as_bytes() {
-            self.position += s.len();
+        let end = self.position + s.len();
+        if self.expected.as_bytes().get(self.position..end) == Some(s.as_bytes()) {
+            self.position = end;
             Ok(())
         } else {
             Err(std::fmt::Error)
@@ -236,94 +796,204 @@ pub(crate) fn display_eq<D: fmt::Display>(expected: &str, display: D) -> bool {
         expected,
         position: 0,
     };
-    write!(buf, "{}", display).is_ok()
+    write!(buf, "{}", display).is_ok() && buf.position == expected.len()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn split(source: &str) -> Vec<&str> {
+        split_words_on_uppercase(source, ParseOptions::default()).collect()
+    }
+
     #[test]
     fn test_split_words_on_uppercase_one_word_lower() {
-        let words: Vec<_> = split_words_on_uppercase("abc").collect();
+        let words = split("abc");
         assert_eq!(vec!["abc"], words);
     }
 
     #[test]
     fn test_split_words_on_uppercase_two_words_lower() {
-        let words: Vec<_> = split_words_on_uppercase("abcDef").collect();
+        let words = split("abcDef");
         assert_eq!(vec!["abc", "Def"], words);
     }
     #[test]
     fn test_split_words_on_uppercase_two_words_upper() {
-        let words: Vec<_> = split_words_on_uppercase("AbcDef").collect();
+        let words = split("AbcDef");
         assert_eq!(vec!["Abc", "Def"], words);
     }
     #[test]
     fn test_split_words_on_uppercase_consecutive_uppers() {
-        let words: Vec<_> = split_words_on_uppercase("ABCdef").collect();
-        assert_eq!(vec!["A", "B", "Cdef"], words);
+        let words = split("ABCdef");
+        assert_eq!(vec!["AB", "Cdef"], words);
     }
 
     #[test]
-    fn test_split_words_on_uppercase_single_letter_lower() {
-        let words: Vec<_> = split_words_on_uppercase("a").collect();
-        assert_eq!(vec!["a"], words);
+    fn test_split_words_on_uppercase_acronyms() {
+        let words = split("HTTPServer");
+        assert_eq!(vec!["HTTP", "Server"], words);
+        let words = split("parseXMLDoc");
+        assert_eq!(vec!["parse", "XML", "Doc"], words);
+        let words = split("loadURL");
+        assert_eq!(vec!["load", "URL"], words);
+        let words = split("ABC");
+        assert_eq!(vec!["ABC"], words);
     }
 
     #[test]
-    fn test_split_words_on_uppercase_single_letter_upper() {
-        let words: Vec<_> = split_words_on_uppercase("A").collect();
-        assert_eq!(vec!["A"], words);
+    fn test_split_words_on_uppercase_split_letters() {
+        let options = ParseOptions::default().acronyms(AcronymPolicy::SplitLetters);
+        let words: Vec<_> = split_words_on_uppercase("ABCdef", options).collect();
+        assert_eq!(vec!["A", "B", "Cdef"], words);
+        let words: Vec<_> = split_words_on_uppercase("HTTPServer", options).collect();
+        assert_eq!(vec!["H", "T", "T", "P", "Server"], words);
     }
 
     #[test]
-    fn test_split_words_on_uppercase_kanji() {
-        let words: Vec<_> = split_words_on_uppercase("こんにちは").collect();
-        assert_eq!(vec!["こんにちは"], words);
+    fn test_split_words_on_uppercase_digits() {
+        let attach = ParseOptions::default();
+        let start_word = ParseOptions::default().digits(DigitPolicy::StartWord);
+        let standalone = ParseOptions::default().digits(DigitPolicy::Standalone);
+        for &(source, options, expected) in &[
+            ("utf8Decoder", attach, &["utf8", "Decoder"][..]),
+            ("utf8Decoder", start_word, &["utf", "8", "Decoder"]),
+            ("utf8Decoder", standalone, &["utf", "8", "Decoder"]),
+            ("i18nKey", attach, &["i18n", "Key"]),
+            ("i18nKey", start_word, &["i", "18n", "Key"]),
+            ("i18nKey", standalone, &["i", "18", "n", "Key"]),
+            ("version2Id", attach, &["version2", "Id"]),
+            ("version2Id", start_word, &["version", "2", "Id"]),
+            ("Vec3", attach, &["Vec3"]),
+            ("Vec3", standalone, &["Vec", "3"]),
+            ("HTTP2Server", standalone, &["HTTP", "2", "Server"]),
+        ] {
+            let words: Vec<_> = split_words_on_uppercase(source, options).collect();
+            assert_eq!(expected, &words[..], "{} with {:?}", source, options.digits);
+        }
     }
 
     #[test]
-    fn test_split_words_on_uppercase_kanji_mixed_upper() {
-        let words: Vec<_> = split_words_on_uppercase("こAんAにAちAはA").collect();
-        assert_eq!(vec!["こ","Aん","Aに","Aち","Aは", "A"], words);
+    fn test_split_words_on_uppercase_graphemes() {
+        let words = split("HTTPE\u{301}cole");
+        assert_eq!(vec!["HTTP", "E\u{301}cole"], words);
+        let words = split("caf\u{E9}Cre\u{300}me");
+        assert_eq!(vec!["caf\u{E9}", "Cre\u{300}me"], words);
+        let options = ParseOptions::default().digits(DigitPolicy::Standalone);
+        let words: Vec<_> = split_words_on_uppercase("keycap1\u{20E3}Value", options).collect();
+        assert_eq!(vec!["keycap", "1\u{20E3}", "Value"], words);
     }
 
     #[test]
-    fn test_is_lower_case_delimited_lower_one_word() {
-        assert!(is_lower_case_delimited("hello", '+'));
+    fn test_split_delimited_digits() {
+        let words: Vec<_> = split_delimited("ipv4_addr", "_", ParseOptions::default()).collect();
+        assert_eq!(vec!["ipv4", "addr"], words);
+        let options = ParseOptions::default().digits(DigitPolicy::StartWord);
+        let words: Vec<_> = split_delimited("ipv4_addr", "_", options).collect();
+        assert_eq!(vec!["ipv", "4", "addr"], words);
+        let options = ParseOptions::default().digits(DigitPolicy::Standalone);
+        let words: Vec<_> = split_delimited("4ipv_addr", "_", options).collect();
+        assert_eq!(vec!["4", "ipv", "addr"], words);
     }
 
     #[test]
-    fn test_is_lower_case_delimited_lower_matching_delimiter() {
-        assert!(is_lower_case_delimited("hello+bye", '+'));
-        assert!(is_lower_case_delimited("hello_bye", '_'));
+    fn test_split_words_on_uppercase_single_letter_lower() {
+        let words = split("a");
+        assert_eq!(vec!["a"], words);
     }
 
     #[test]
-    fn test_is_lower_case_delimited_lower_wrong_delimiter() {
-        assert!(!is_lower_case_delimited("hello+bye", '-'));
+    fn test_split_words_on_uppercase_single_letter_upper() {
+        let words = split("A");
+        assert_eq!(vec!["A"], words);
     }
 
     #[test]
-    fn test_is_lower_case_delimited_lower_trailing_delimiter() {
-        assert!(!is_lower_case_delimited("hello+", '+'));
+    fn test_split_words_on_uppercase_kanji() {
+        let words = split("こんにちは");
+        assert_eq!(vec!["こんにちは"], words);
     }
 
     #[test]
-    fn test_is_lower_case_delimited_lower_leading_delimiter() {
-        assert!(!is_lower_case_delimited("+hello", '+'));
+    fn test_split_words_on_uppercase_kanji_mixed_upper() {
+        let words = split("こAんAにAちAはA");
+        assert_eq!(vec!["こ","Aん","Aに","Aち","Aは", "A"], words);
     }
 
     #[test]
-    fn test_is_lower_case_delimited_upper() {
-        assert!(!is_lower_case_delimited("HELLO", '-'));
-        assert!(!is_lower_case_delimited("HELLO-BYE", '-'));
+    fn test_split_words_on_script() {
+        let options = ParseOptions::default().scripts(ScriptPolicy::Split);
+        for &(source, expected) in &[
+            ("東京Tower", &["東京", "Tower"][..]),
+            ("서울station", &["서울", "station"]),
+            ("tokyo東京タワーSky", &["tokyo", "東京タワー", "Sky"]),
+            ("東京2Tower", &["東京2", "Tower"]),
+            ("서울東京", &["서울", "東京"]),
+            ("2東京", &["2東京"]),
+        ] {
+            let words: Vec<_> = split_words_on_uppercase(source, options).collect();
+            assert_eq!(expected, &words[..], "{}", source);
+        }
+        assert_eq!(vec!["서울station"], split("서울station"));
+        let words: Vec<_> = split_delimited("東京tower_x", "_", options).collect();
+        assert_eq!(vec!["東京", "tower", "x"], words);
+    }
+
+    #[test]
+    fn test_validate_delimited_lower() {
+        assert!(validate_delimited("hello", "+", WordCasing::Lower).is_ok());
+        assert!(validate_delimited("hello+bye", "+", WordCasing::Lower).is_ok());
+        assert!(validate_delimited("hello_bye", "_", WordCasing::Lower).is_ok());
+        assert_eq!(
+            Err(CaseError::new(5, '+', CaseErrorKind::NonAlphanumeric)),
+            validate_delimited("hello+bye", "-", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(5, '+', CaseErrorKind::TrailingDelimiter)),
+            validate_delimited("hello+", "+", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(0, '+', CaseErrorKind::LeadingDelimiter)),
+            validate_delimited("+hello", "+", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(0, 'H', CaseErrorKind::Uppercase)),
+            validate_delimited("HELLO", "-", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(0, 'H', CaseErrorKind::Uppercase)),
+            validate_delimited("HELLO-BYE", "-", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(6, '_', CaseErrorKind::RepeatedDelimiter)),
+            validate_delimited("hello__there", "_", WordCasing::Lower)
+        );
     }
 
     #[test]
-    fn test_is_lower_case_delimited_repeated_delimiter() {
-        assert!(!is_lower_case_delimited("HELLO__THERE", '_'));
+    fn test_validate_delimited_upper() {
+        assert!(validate_delimited("HELLO", "_", WordCasing::Upper).is_ok());
+        assert!(validate_delimited("HELLO_BYE_2", "_", WordCasing::Upper).is_ok());
+        assert_eq!(
+            Err(CaseError::new(6, 'b', CaseErrorKind::Lowercase)),
+            validate_delimited("HELLO_bye", "_", WordCasing::Upper)
+        );
+        assert_eq!(
+            Err(CaseError::new(6, '_', CaseErrorKind::RepeatedDelimiter)),
+            validate_delimited("HELLO__BYE", "_", WordCasing::Upper)
+        );
+        assert_eq!(
+            Err(CaseError::new(0, '_', CaseErrorKind::LeadingDelimiter)),
+            validate_delimited("_HELLO", "_", WordCasing::Upper)
+        );
+        assert_eq!(
+            Err(CaseError::new(5, '_', CaseErrorKind::TrailingDelimiter)),
+            validate_delimited("HELLO_", "_", WordCasing::Upper)
+        );
+        assert_eq!(
+            Err(CaseError::new(5, '-', CaseErrorKind::NonAlphanumeric)),
+            validate_delimited("HELLO-BYE", "_", WordCasing::Upper)
+        );
     }
 
     fn upper_case() -> Word<'static> { Word::upper_case("HELLO") }
@@ -332,6 +1002,23 @@ mod tests {
     fn mixed_case1() -> Word<'static> { Word::mixed_case("hEllO") }
     fn mixed_case2() -> Word<'static> { Word::mixed_case("HeLLo") }
 
+    #[test]
+    fn test_word_kind() {
+        assert_eq!(WordKind::Upper, upper_case().kind());
+        assert_eq!(WordKind::Lower, lower_case().kind());
+        assert_eq!(WordKind::Capitalized, capitalized().kind());
+        assert_eq!(WordKind::Mixed, mixed_case1().kind());
+        assert_eq!(WordKind::Mixed, mixed_case2().kind());
+        assert_eq!(WordKind::Acronym, hump_word("XML").kind());
+        assert_eq!(WordKind::Capitalized, hump_word("X").kind());
+        assert_eq!(WordKind::Numeric, Word::lower_case("42").kind());
+        assert_eq!(WordKind::Numeric, Word::upper_case("42").kind());
+        assert_eq!(WordKind::Lower, Word::lower_case("東京").kind());
+        assert_eq!(WordKind::Lower, Word::mixed_case("max").kind());
+        assert_eq!(WordKind::Capitalized, Word::mixed_case("Max").kind());
+        assert_eq!(WordKind::Upper, Word::mixed_case("MAX").kind());
+    }
+
     #[test]
     fn test_word_write_capitalized() {
         for word in &[upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()] {
@@ -352,17 +1039,148 @@ mod tests {
 
     #[test]
     fn test_write_lower_delimited() {
-        let words = vec![upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()];
+        let words = [upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()];
+        let options = RenderOptions::default();
         let mut output = String::new();
-        assert!(write_lower_delimited(words.iter(), &mut output, '_').is_ok());
+        assert!(write_lower_delimited(words.iter(), &mut output, '_', &options).is_ok());
         assert_eq!("hello_hello_hello_hello_hello", &output);
     }
 
+    #[test]
+    fn test_validate_delimited_capitalized() {
+        assert!(validate_delimited("Hello", "-", WordCasing::Capitalized).is_ok());
+        assert!(validate_delimited("Hello-Bye-2", "-", WordCasing::Capitalized).is_ok());
+        assert_eq!(
+            Err(CaseError::new(6, 'b', CaseErrorKind::Lowercase)),
+            validate_delimited("Hello-bye", "-", WordCasing::Capitalized)
+        );
+        assert_eq!(
+            Err(CaseError::new(7, 'Y', CaseErrorKind::Uppercase)),
+            validate_delimited("Hello-BYE", "-", WordCasing::Capitalized)
+        );
+        assert_eq!(
+            Err(CaseError::new(6, '-', CaseErrorKind::RepeatedDelimiter)),
+            validate_delimited("Hello--Bye", "-", WordCasing::Capitalized)
+        );
+        assert_eq!(
+            Err(CaseError::new(5, '-', CaseErrorKind::TrailingDelimiter)),
+            validate_delimited("Hello-", "-", WordCasing::Capitalized)
+        );
+        assert_eq!(
+            Err(CaseError::new(5, '_', CaseErrorKind::NonAlphanumeric)),
+            validate_delimited("Hello_Bye", "-", WordCasing::Capitalized)
+        );
+    }
+
+    #[test]
+    fn test_validate_delimited_multi_char() {
+        assert!(validate_delimited("", "::", WordCasing::Lower).is_ok());
+        assert!(validate_delimited("hello", "::", WordCasing::Lower).is_ok());
+        assert!(validate_delimited("hello::bye", "::", WordCasing::Lower).is_ok());
+        assert!(validate_delimited("HELLO__BYE", "__", WordCasing::Upper).is_ok());
+        assert!(validate_delimited("Hello.Bye", ".", WordCasing::Capitalized).is_ok());
+        assert_eq!(
+            Err(CaseError::new(5, ':', CaseErrorKind::NonAlphanumeric)),
+            validate_delimited("hello:bye", "::", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(7, ':', CaseErrorKind::NonAlphanumeric)),
+            validate_delimited("hello:::bye", "::", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(0, ':', CaseErrorKind::LeadingDelimiter)),
+            validate_delimited("::hello", "::", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(5, ':', CaseErrorKind::TrailingDelimiter)),
+            validate_delimited("hello::", "::", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(7, 'B', CaseErrorKind::Uppercase)),
+            validate_delimited("hello::Bye", "::", WordCasing::Lower)
+        );
+        assert_eq!(
+            Err(CaseError::new(7, '_', CaseErrorKind::NonAlphanumeric)),
+            validate_delimited("HELLO___BYE", "__", WordCasing::Upper)
+        );
+        assert_eq!(
+            Err(CaseError::new(7, 'Y', CaseErrorKind::Uppercase)),
+            validate_delimited("Hello.BYE", ".", WordCasing::Capitalized)
+        );
+    }
+
+    #[test]
+    fn test_word_write_upper_case() {
+        for word in &[upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()] {
+            let mut output = String::new();
+            assert!(word.write_uppercase(&mut output).is_ok());
+            assert_eq!("HELLO", &output);
+        }
+    }
+
+    #[test]
+    fn test_write_graphemes() {
+        let mut output = String::new();
+        Word::lower_case("e\u{301}cole").write_capitalized(&mut output).unwrap();
+        assert_eq!("E\u{301}cole", output);
+        output.clear();
+        Word::capitalized("E\u{301}cole").write_lowercase(&mut output).unwrap();
+        assert_eq!("e\u{301}cole", output);
+        output.clear();
+        Word::upper_case("\u{1F469}\u{200D}\u{1F4BB}DEV").write_capitalized(&mut output).unwrap();
+        assert_eq!("\u{1F469}\u{200D}\u{1F4BB}dev", output);
+        assert!(hump_word("E\u{301}").is_valid());
+        output.clear();
+        hump_word("E\u{301}").write_lowercase(&mut output).unwrap();
+        assert_eq!("e\u{301}", output);
+    }
+
+    #[test]
+    fn test_write_titlecase() {
+        let capitalized = |word: Word| {
+            let mut output = String::new();
+            word.write_capitalized(&mut output).unwrap();
+            output
+        };
+        assert_eq!("\u{1C5}ungla", capitalized(Word::lower_case("\u{1C6}ungla")));
+        assert_eq!("\u{1C5}ungla", capitalized(Word::upper_case("\u{1C4}UNGLA")));
+        assert_eq!("Ssa", capitalized(Word::lower_case("\u{DF}a")));
+        assert_eq!("Fish", capitalized(Word::lower_case("\u{FB01}sh")));
+        assert_eq!("\u{1C5}\u{301}a", capitalized(Word::lower_case("\u{1C6}\u{301}a")));
+    }
+
+    #[test]
+    fn test_write_upper_delimited() {
+        let words = [upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()];
+        let mut output = String::new();
+        assert!(write_upper_delimited(words.iter(), &mut output, '_', &RenderOptions::default()).is_ok());
+        assert_eq!("HELLO_HELLO_HELLO_HELLO_HELLO", &output);
+    }
+
+    #[test]
+    fn test_write_capitalized_delimited() {
+        let words = [upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()];
+        let options = RenderOptions::default();
+        let mut output = String::new();
+        assert!(write_capitalized_delimited(words.iter(), &mut output, '-', &options).is_ok());
+        assert_eq!("Hello-Hello-Hello-Hello-Hello", &output);
+    }
+
+    #[test]
+    fn test_write_delimited() {
+        let words = [upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()];
+        let options = RenderOptions::default();
+        let mut output = String::new();
+        assert!(write_delimited(words.iter(), &mut output, "::", WordCasing::Capitalized, &options).is_ok());
+        assert_eq!("Hello::Hello::Hello::Hello::Hello", &output);
+    }
+
     #[test]
     fn test_write_pascal_case() {
-        let words = vec![upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()];
+        let words = [upper_case(), lower_case(), mixed_case1(), mixed_case2(), capitalized()];
+        let options = RenderOptions::default();
         let mut output = String::new();
-        assert!(write_pascal_case(words.iter(), &mut output).is_ok());
+        assert!(write_pascal_case(words.iter(), &mut output, &options).is_ok());
         assert_eq!("HelloHelloHelloHelloHello", &output);
     }
 
@@ -372,5 +1190,15 @@ mod tests {
         assert!(display_eq("hi", "hi"));
         assert!(!display_eq("0", 1));
         assert!(!display_eq("HI", "hi"));
+        assert!(!display_eq("hello", "hel"));
+        assert!(!display_eq("hel", "hello"));
+    }
+
+    #[test]
+    fn test_display_eq_multiple_writes() {
+        let words = vec![lower_case(), capitalized()];
+        let snake = crate::SnakeCase(CaseValue::Words(words));
+        assert!(display_eq("hello_hello", &snake));
+        assert!(!display_eq("hello_hello_hello", &snake));
     }
 }
@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+
+/// A programming language that names may be generated for. Each language has its own reserved
+/// words and its own way of using one as an identifier.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Keywords become raw identifiers, `r#type`. The few keywords that cannot be raw
+    /// identifiers get a trailing underscore instead, `self_`.
+    Rust,
+    /// Keywords get a trailing underscore, `class_`, following PEP 8.
+    Python,
+    /// Keywords get a trailing underscore, `delete_`.
+    TypeScript,
+    /// Keywords are quoted, `"select"`. Keywords are matched ignoring case.
+    Sql,
+}
+
+/// An identifier that may have been escaped to avoid a reserved word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Escaped<'a> {
+    pub ident: Cow<'a, str>,
+    /// Whether `ident` was changed because it was a reserved word
+    pub escaped: bool,
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords that are not allowed as raw identifiers
+const RUST_NON_RAW: &[&str] = &["crate", "self", "Self", "super"];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if",
+    "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try",
+    "while", "with", "yield",
+];
+
+const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default",
+    "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for", "function",
+    "if", "implements", "import", "in", "instanceof", "interface", "let", "new", "null",
+    "package", "private", "protected", "public", "return", "static", "super", "switch", "this",
+    "throw", "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+const SQL_KEYWORDS: &[&str] = &[
+    "all", "alter", "and", "any", "as", "asc", "begin", "between", "by", "case", "check",
+    "column", "commit", "constraint", "create", "cross", "current", "database", "default",
+    "delete", "desc", "distinct", "drop", "else", "end", "exists", "false", "fetch", "for",
+    "foreign", "from", "full", "grant", "group", "having", "in", "index", "inner", "insert",
+    "intersect", "into", "is", "join", "key", "left", "like", "limit", "natural", "not", "null",
+    "offset", "on", "or", "order", "outer", "primary", "references", "revoke", "right",
+    "rollback", "row", "select", "set", "table", "then", "to", "trigger", "true", "union",
+    "unique", "update", "user", "using", "values", "view", "when", "where", "with",
+];
+
+impl Language {
+    /// Whether `ident` is a reserved word in this language
+    pub fn is_keyword(self, ident: &str) -> bool {
+        match self {
+            Language::Rust => RUST_KEYWORDS.contains(&ident),
+            Language::Python => PYTHON_KEYWORDS.contains(&ident),
+            Language::TypeScript => TYPESCRIPT_KEYWORDS.contains(&ident),
+            Language::Sql => SQL_KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(ident)),
+        }
+    }
+
+    /// Escape `ident` if it is a reserved word in this language, so that it can be used as an
+    /// identifier.
+    ///
+    /// ```
+    /// use camel_kebab::Language;
+    ///
+    /// assert_eq!("r#type", Language::Rust.escape("type").ident);
+    /// assert_eq!("class_", Language::Python.escape("class").ident);
+    /// assert_eq!("\"select\"", Language::Sql.escape("select").ident);
+    /// assert!(!Language::Rust.escape("name").escaped);
+    /// ```
+    pub fn escape(self, ident: &str) -> Escaped<'_> {
+        if !self.is_keyword(ident) {
+            return Escaped { ident: Cow::Borrowed(ident), escaped: false };
+        }
+        let mut escaped = ident.to_string();
+        self.escape_in_place(&mut escaped);
+        Escaped { ident: Cow::Owned(escaped), escaped: true }
+    }
+
+    /// Escape `ident` where it is, if it is a reserved word in this language. Returns whether it
+    /// was escaped.
+    pub(crate) fn escape_in_place(self, ident: &mut String) -> bool {
+        if !self.is_keyword(ident) {
+            return false;
+        }
+        match self {
+            Language::Rust if RUST_NON_RAW.contains(&ident.as_str()) => ident.push('_'),
+            Language::Rust => ident.insert_str(0, "r#"),
+            Language::Python | Language::TypeScript => ident.push('_'),
+            Language::Sql => {
+                // Keywords never contain a quote, so there is nothing to double
+                ident.insert(0, '"');
+                ident.push('"');
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust() {
+        assert_eq!("r#match", Language::Rust.escape("match").ident);
+        assert_eq!("r#async", Language::Rust.escape("async").ident);
+        assert_eq!("self_", Language::Rust.escape("self").ident);
+        assert_eq!("Self_", Language::Rust.escape("Self").ident);
+        assert_eq!("class", Language::Rust.escape("class").ident);
+        assert!(!Language::Rust.escape("Type").escaped);
+    }
+
+    #[test]
+    fn test_python_and_typescript() {
+        assert_eq!("None_", Language::Python.escape("None").ident);
+        assert_eq!("lambda_", Language::Python.escape("lambda").ident);
+        assert_eq!("match", Language::Python.escape("match").ident);
+        assert_eq!("delete_", Language::TypeScript.escape("delete").ident);
+        assert_eq!("interface_", Language::TypeScript.escape("interface").ident);
+        assert!(!Language::TypeScript.escape("lambda").escaped);
+    }
+
+    #[test]
+    fn test_sql() {
+        assert!(Language::Sql.is_keyword("SELECT"));
+        assert_eq!("\"Order\"", Language::Sql.escape("Order").ident);
+        assert_eq!("\"user\"", Language::Sql.escape("user").ident);
+        let escaped = Language::Sql.escape("customer");
+        assert_eq!(Escaped { ident: Cow::Borrowed("customer"), escaped: false }, escaped);
+    }
+}
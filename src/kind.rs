@@ -0,0 +1,342 @@
+use crate::internals::Case as _;
+use crate::{
+    AdaCase, Case, CaseError, CamelCase, CobolCase, DotCase, FlatCase, HttpHeaderCase, KebabCase,
+    ParseCaseKindError, ParseOptions, PascalCase, PathCase, RenderOptions, ScreamingSnakeCase,
+    SnakeCase, TrainCase, UpperFlatCase, WordList,
+};
+use std::fmt;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+/// Identifies one of the cases supported by this crate, for when the case is not known until
+/// runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CaseKind {
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    Cobol,
+    Camel,
+    Pascal,
+    Ada,
+    Train,
+    HttpHeader,
+    Dot,
+    Path,
+    Flat,
+    UpperFlat,
+}
+
+impl CaseKind {
+    /// Every `CaseKind`. Where a string is valid in more than one case, the earlier one is the
+    /// more likely intended.
+    pub const ALL: [CaseKind; 13] = [
+        CaseKind::Snake,
+        CaseKind::ScreamingSnake,
+        CaseKind::Kebab,
+        CaseKind::Cobol,
+        CaseKind::Camel,
+        CaseKind::Pascal,
+        CaseKind::Ada,
+        CaseKind::Train,
+        CaseKind::HttpHeader,
+        CaseKind::Dot,
+        CaseKind::Path,
+        CaseKind::Flat,
+        CaseKind::UpperFlat,
+    ];
+
+    /// Equivalent to `Case::validate` for the corresponding type
+    pub fn validate(self, source: &str) -> Result<(), CaseError> {
+        match self {
+            CaseKind::Snake => SnakeCase::validate(source),
+            CaseKind::ScreamingSnake => ScreamingSnakeCase::validate(source),
+            CaseKind::Kebab => KebabCase::validate(source),
+            CaseKind::Cobol => CobolCase::validate(source),
+            CaseKind::Camel => CamelCase::validate(source),
+            CaseKind::Pascal => PascalCase::validate(source),
+            CaseKind::Ada => AdaCase::validate(source),
+            CaseKind::Train => TrainCase::validate(source),
+            CaseKind::HttpHeader => HttpHeaderCase::validate(source),
+            CaseKind::Dot => DotCase::validate(source),
+            CaseKind::Path => PathCase::validate(source),
+            CaseKind::Flat => FlatCase::validate(source),
+            CaseKind::UpperFlat => UpperFlatCase::validate(source),
+        }
+    }
+
+    /// Equivalent to `Case::str_is_case` for the corresponding type
+    #[inline]
+    pub fn str_is_case(self, source: &str) -> bool {
+        self.validate(source).is_ok()
+    }
+
+    /// Whether `source` is in this case. This is the check that `detect_case` makes for each
+    /// kind, so a kind is in `Detection::matches` exactly when this is true.
+    #[inline]
+    pub fn is(self, source: &str) -> bool {
+        self.str_is_case(source)
+    }
+
+    /// The name of this case, as used by `Display` and `FromStr`, such as `snake` or
+    /// `screaming-snake`
+    pub fn name(self) -> &'static str {
+        match self {
+            CaseKind::Snake => "snake",
+            CaseKind::ScreamingSnake => "screaming-snake",
+            CaseKind::Kebab => "kebab",
+            CaseKind::Cobol => "cobol",
+            CaseKind::Camel => "camel",
+            CaseKind::Pascal => "pascal",
+            CaseKind::Ada => "ada",
+            CaseKind::Train => "train",
+            CaseKind::HttpHeader => "http-header",
+            CaseKind::Dot => "dot",
+            CaseKind::Path => "path",
+            CaseKind::Flat => "flat",
+            CaseKind::UpperFlat => "upper-flat",
+        }
+    }
+
+    /// Split `source` into words, assuming that it is in this case
+    pub fn split<'a>(self, source: &'a str, options: ParseOptions) -> WordList<'a> {
+        let words = match self {
+            CaseKind::Snake => SnakeCase::split_joined(source, options).collect(),
+            CaseKind::ScreamingSnake => ScreamingSnakeCase::split_joined(source, options).collect(),
+            CaseKind::Kebab => KebabCase::split_joined(source, options).collect(),
+            CaseKind::Cobol => CobolCase::split_joined(source, options).collect(),
+            CaseKind::Camel => CamelCase::split_joined(source, options).collect(),
+            CaseKind::Pascal => PascalCase::split_joined(source, options).collect(),
+            CaseKind::Ada => AdaCase::split_joined(source, options).collect(),
+            CaseKind::Train => TrainCase::split_joined(source, options).collect(),
+            CaseKind::HttpHeader => HttpHeaderCase::split_joined(source, options).collect(),
+            CaseKind::Dot => DotCase::split_joined(source, options).collect(),
+            CaseKind::Path => PathCase::split_joined(source, options).collect(),
+            CaseKind::Flat => FlatCase::split_joined(source, options).collect(),
+            CaseKind::UpperFlat => UpperFlatCase::split_joined(source, options).collect(),
+        };
+        WordList(words)
+    }
+
+    /// Write `words` in this case
+    pub fn render(self, words: &WordList) -> String {
+        let mut rendered = String::new();
+        self.write_words(words, &mut rendered, &RenderOptions::default())
+            .expect("writing to a String cannot fail");
+        rendered
+    }
+
+    /// Equivalent to `Case::write_words` for the corresponding type
+    fn write_words<W: fmt::Write>(
+        self,
+        words: &WordList,
+        f: &mut W,
+        options: &RenderOptions,
+    ) -> Result<(), fmt::Error> {
+        let words = words.0.iter();
+        match self {
+            CaseKind::Snake => SnakeCase::write_words(words, f, options),
+            CaseKind::ScreamingSnake => ScreamingSnakeCase::write_words(words, f, options),
+            CaseKind::Kebab => KebabCase::write_words(words, f, options),
+            CaseKind::Cobol => CobolCase::write_words(words, f, options),
+            CaseKind::Camel => CamelCase::write_words(words, f, options),
+            CaseKind::Pascal => PascalCase::write_words(words, f, options),
+            CaseKind::Ada => AdaCase::write_words(words, f, options),
+            CaseKind::Train => TrainCase::write_words(words, f, options),
+            CaseKind::HttpHeader => HttpHeaderCase::write_words(words, f, options),
+            CaseKind::Dot => DotCase::write_words(words, f, options),
+            CaseKind::Path => PathCase::write_words(words, f, options),
+            CaseKind::Flat => FlatCase::write_words(words, f, options),
+            CaseKind::UpperFlat => UpperFlatCase::write_words(words, f, options),
+        }
+    }
+}
+
+impl fmt::Display for CaseKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name of a case. Letter case, a `case` suffix and any `-`, `_` or space are ignored,
+/// so `kebab`, `kebab-case`, `KebabCase` and `KEBAB_CASE` are all `CaseKind::Kebab`. The names
+/// `constant` and `header` are accepted for screaming snake case and HTTP header case.
+///
+/// ```
+/// use camel_kebab::CaseKind;
+///
+/// assert_eq!(Ok(CaseKind::ScreamingSnake), "screaming_snake_case".parse());
+/// assert_eq!(Ok(CaseKind::HttpHeader), "HttpHeader".parse());
+/// assert!("shouting".parse::<CaseKind>().is_err());
+/// ```
+impl FromStr for CaseKind {
+    type Err = ParseCaseKindError;
+
+    fn from_str(name: &str) -> Result<CaseKind, ParseCaseKindError> {
+        let mut key: String = name
+            .chars()
+            .filter(|ch| !matches!(ch, '-' | '_' | ' '))
+            .flat_map(char::to_lowercase)
+            .collect();
+        if key.len() > "case".len() && key.ends_with("case") {
+            key.truncate(key.len() - "case".len());
+        }
+        let kind = match key.as_str() {
+            "constant" => Some(CaseKind::ScreamingSnake),
+            "header" => Some(CaseKind::HttpHeader),
+            _ => CaseKind::ALL.iter().copied().find(|kind| kind.name().replace('-', "") == key),
+        };
+        kind.ok_or_else(|| ParseCaseKindError { name: name.to_owned() })
+    }
+}
+
+/// Convert `source`, which must be in case `from`, to case `to`.
+///
+/// ```
+/// use camel_kebab::{convert, CaseKind};
+///
+/// let from: CaseKind = "snake_case".parse().unwrap();
+/// let to: CaseKind = "camelCase".parse().unwrap();
+/// assert_eq!("maxSize", convert("max_size", from, to).unwrap());
+/// assert!(convert("maxSize", from, to).is_err());
+/// ```
+pub fn convert(source: &str, from: CaseKind, to: CaseKind) -> Result<String, CaseError> {
+    from.validate(source)?;
+    Ok(to.render(&from.split(source, ParseOptions::default())))
+}
+
+/// Render `source` in every case, in the order of `CaseKind::ALL`. This is useful for templates,
+/// which can use whichever case they need. The words are found as by `WordList::parse_lenient`.
+///
+/// ```
+/// use camel_kebab::{render_all, CaseKind};
+///
+/// let all = render_all("maxSize");
+/// assert_eq!((CaseKind::Snake, "max_size".to_owned()), all[0]);
+/// assert_eq!((CaseKind::UpperFlat, "MAXSIZE".to_owned()), all[12]);
+/// ```
+pub fn render_all(source: &str) -> Vec<(CaseKind, String)> {
+    let words = WordList::parse_lenient(source);
+    CaseKind::ALL.iter().map(|&kind| (kind, kind.render(&words))).collect()
+}
+
+/// A set of `CaseKind`s
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CaseKindSet(u16);
+
+impl CaseKindSet {
+    #[inline]
+    pub fn new() -> CaseKindSet {
+        CaseKindSet(0)
+    }
+
+    #[inline]
+    pub fn insert(&mut self, kind: CaseKind) {
+        self.0 |= 1 << kind as u16;
+    }
+
+    #[inline]
+    pub fn contains(&self, kind: CaseKind) -> bool {
+        self.0 & (1 << kind as u16) != 0
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterate over the kinds in the set, in the order of `CaseKind::ALL`
+    pub fn iter(&self) -> impl Iterator<Item = CaseKind> {
+        let set = *self;
+        CaseKind::ALL.iter().copied().filter(move |&kind| set.contains(kind))
+    }
+}
+
+impl FromIterator<CaseKind> for CaseKindSet {
+    fn from_iter<I: IntoIterator<Item = CaseKind>>(iter: I) -> Self {
+        let mut set = CaseKindSet::new();
+        for kind in iter {
+            set.insert(kind);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for CaseKindSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_round_trip() {
+        for &kind in &CaseKind::ALL {
+            assert_eq!(Ok(kind), kind.to_string().parse());
+            assert_eq!(Ok(kind), format!("{}-case", kind).parse());
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(CaseKind::Snake), "snake".parse());
+        assert_eq!(Ok(CaseKind::Kebab), "kebab-case".parse());
+        assert_eq!(Ok(CaseKind::Kebab), "KebabCase".parse());
+        assert_eq!(Ok(CaseKind::UpperFlat), "UPPER_FLAT_CASE".parse());
+        assert_eq!(Ok(CaseKind::ScreamingSnake), "constant".parse());
+        assert_eq!(Ok(CaseKind::HttpHeader), "header-case".parse());
+        assert_eq!(Ok(CaseKind::Path), "path case".parse());
+        let error = "case".parse::<CaseKind>().unwrap_err();
+        assert_eq!("unknown case \"case\"", error.to_string());
+        assert!("".parse::<CaseKind>().is_err());
+        assert!("snakes".parse::<CaseKind>().is_err());
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!("max_size", convert("MaxSize", CaseKind::Pascal, CaseKind::Snake).unwrap());
+        assert_eq!("Content-MD5", convert("content.md5", CaseKind::Dot, CaseKind::HttpHeader).unwrap());
+        assert_eq!("XMLHTTP", convert("XMLHttp", CaseKind::Pascal, CaseKind::UpperFlat).unwrap());
+        let error = convert("max_size", CaseKind::Kebab, CaseKind::Snake).unwrap_err();
+        assert_eq!((3, '_'), (error.offset, error.ch));
+    }
+
+    #[test]
+    fn test_is() {
+        for &kind in &CaseKind::ALL {
+            assert!(kind.is(""));
+        }
+        assert!(CaseKind::Camel.is("maxSize"));
+        assert!(!CaseKind::Pascal.is("maxSize"));
+    }
+
+    #[test]
+    fn test_render_all() {
+        let all = render_all("__XMLHttp request__");
+        let rendered: Vec<_> = all.iter().map(|(kind, s)| (kind.name(), s.as_str())).collect();
+        let expected = vec![
+            ("snake", "xml_http_request"),
+            ("screaming-snake", "XML_HTTP_REQUEST"),
+            ("kebab", "xml-http-request"),
+            ("cobol", "XML-HTTP-REQUEST"),
+            ("camel", "xmlHttpRequest"),
+            ("pascal", "XmlHttpRequest"),
+            ("ada", "Xml_Http_Request"),
+            ("train", "Xml-Http-Request"),
+            ("http-header", "Xml-Http-Request"),
+            ("dot", "xml.http.request"),
+            ("path", "xml/http/request"),
+            ("flat", "xmlhttprequest"),
+            ("upper-flat", "XMLHTTPREQUEST"),
+        ];
+        assert_eq!(expected, rendered);
+    }
+}
@@ -1,14 +1,61 @@
+use std::borrow::Cow;
+use std::{fmt, io};
+
 #[macro_use]
 mod macros;
 mod internals;
+pub use internals::{Word, WordKind};
 mod camel;
-pub use camel::CamelCase;
+pub use camel::{CamelCase, CamelCaseExt};
 mod kebab;
-pub use kebab::KebabCase;
+pub use kebab::{KebabCase, KebabCaseExt};
 mod snake;
-pub use snake::SnakeCase;
+pub use snake::{SnakeCase, SnakeCaseExt};
 mod pascal;
-pub use pascal::PascalCase;
+pub use pascal::{PascalCase, PascalCaseExt};
+mod screaming_snake;
+pub use screaming_snake::{ScreamingSnakeCase, ScreamingSnakeCaseExt};
+mod train;
+pub use train::{TrainCase, TrainCaseExt};
+mod header;
+pub use header::{HttpHeaderCase, HttpHeaderCaseExt};
+mod cobol;
+pub use cobol::{CobolCase, CobolCaseExt};
+mod ada;
+pub use ada::{AdaCase, AdaCaseExt};
+mod flat;
+pub use flat::{FlatCase, FlatCaseExt};
+mod upper_flat;
+pub use upper_flat::{UpperFlatCase, UpperFlatCaseExt};
+mod error;
+pub use error::{CaseError, CaseErrorKind, LossyConversion, ParseCaseKindError};
+mod options;
+pub use options::{AcronymPolicy, DigitPolicy, ParseOptions, ScriptPolicy};
+mod dictionary;
+pub use dictionary::Dictionary;
+mod exceptions;
+pub use exceptions::Exceptions;
+mod render;
+pub use render::RenderOptions;
+mod locale;
+pub use locale::Locale;
+mod word_list;
+pub use word_list::WordList;
+mod affixed;
+pub use affixed::Affixed;
+mod keywords;
+pub use keywords::{Escaped, Language};
+mod slug;
+pub use slug::{slugify, slugify_with_limit, GitHubSlugger};
+mod kind;
+pub use kind::{convert, render_all, CaseKind, CaseKindSet};
+mod detect;
+pub use detect::{detect_case, Detection};
+mod delimited;
+pub use delimited::{
+    DelimitedCase, Delimiter, Dot, DotCase, DotCaseExt, DoubleColon, DoubleUnderscore, PathCase,
+    PathCaseExt, Slash, WordCasing,
+};
 
 pub trait Case<'a>: internals::Case<'a> {
     #[inline]
@@ -17,12 +64,32 @@ pub trait Case<'a>: internals::Case<'a> {
         W: IntoIterator<Item = &'a str>,
     {
         let words = words.into_iter()
-            .map(|word| internals::Word::mixed_case(word))
+            .map(internals::Word::mixed_case)
             .collect();
         Self::from_cased_words(words)
     }
 
-    fn str_is_case(source: &str) -> bool;
+    /// Check that `source` is in this case, or find the first character that is not
+    fn validate(source: &str) -> Result<(), CaseError>;
+
+    #[inline]
+    fn str_is_case(source: &str) -> bool {
+        Self::validate(source).is_ok()
+    }
+
+    /// Like `str_as_case`, but on failure, says why `source` is not in this case.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, CaseErrorKind, KebabCase};
+    ///
+    /// let error = KebabCase::try_parse("max--size").unwrap_err();
+    /// assert_eq!((4, CaseErrorKind::RepeatedDelimiter), (error.offset, error.kind));
+    /// ```
+    #[inline]
+    fn try_parse(source: &'a str) -> Result<Self, CaseError> {
+        Self::validate(source)?;
+        Ok(Self::str_as_case_unchecked(source))
+    }
 
     #[inline]
     fn str_as_case(source: &'a str) -> Option<Self> {
@@ -34,12 +101,205 @@ pub trait Case<'a>: internals::Case<'a> {
     }
 
     fn str_as_case_unchecked(source: &'a str) -> Self;
+
+    /// The words of this value, in order. The words of a value that was converted from another
+    /// case are as they were written in that case, so each word's `kind` says how it was cased
+    /// in the source.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, KebabCase};
+    ///
+    /// let kebab = KebabCase::str_as_case("io-2-x").unwrap();
+    /// let words: Vec<_> = kebab.words().map(|word| word.to_string()).collect();
+    /// assert_eq!(vec!["io", "2", "x"], words);
+    /// ```
+    fn words<'s>(&'s self) -> impl Iterator<Item = Word<'s>>
+    where
+        'a: 's,
+    {
+        let (joined, words) = match self.case_value() {
+            internals::CaseValue::Joined(string) => {
+                (Some(Self::split_joined(string, ParseOptions::default())), None)
+            }
+            internals::CaseValue::Words(words) => (None, Some(words.iter().map(Word::reborrow))),
+        };
+        joined.into_iter().flatten().chain(words.into_iter().flatten())
+    }
+
+    /// Display this value with control over how its words are written
+    #[inline]
+    fn display_with<'r>(&'r self, options: RenderOptions<'r>) -> impl fmt::Display + 'r {
+        render::DisplayFn(move |f: &mut fmt::Formatter| internals::write_case(self, f, &options))
+    }
+
+    /// Render this value as an identifier in `language`, escaping it if it is a reserved word.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, KebabCase, Language, SnakeCase};
+    ///
+    /// let snake = SnakeCase::from(KebabCase::str_as_case("type").unwrap());
+    /// let escaped = snake.to_identifier(Language::Rust);
+    /// assert_eq!("r#type", escaped.ident);
+    /// assert!(escaped.escaped);
+    /// ```
+    fn to_identifier(&self, language: Language) -> Escaped<'static>
+    where
+        Self: fmt::Display,
+    {
+        let mut ident = self.to_string();
+        let escaped = language.escape_in_place(&mut ident);
+        Escaped { ident: Cow::Owned(ident), escaped }
+    }
+
+    /// Convert to another case, but only if the words can be read back from the result exactly,
+    /// ignoring how each word is cased.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, CamelCase, KebabCase, SnakeCase};
+    ///
+    /// let snake = SnakeCase::str_as_case("io_2_x").unwrap();
+    /// assert!(snake.clone().try_convert::<CamelCase>().is_err());
+    /// assert_eq!("io-2-x", snake.try_convert::<KebabCase>().unwrap().to_string());
+    /// ```
+    fn try_convert<T>(self) -> Result<T, LossyConversion>
+    where
+        T: Case<'a> + fmt::Display,
+    {
+        let words = self.to_cased_words();
+        let target = T::from_cased_words(words.clone());
+        let rendered = target.to_string();
+        let read_back: Vec<_> = T::split_joined(&rendered, ParseOptions::default()).collect();
+        let exact = words.len() == read_back.len()
+            && words.iter().zip(&read_back).all(|(a, b)| internals::words_eq(a, b));
+        if exact {
+            return Ok(target);
+        }
+        let strings = |words: &[internals::Word]| words.iter().map(|word| word.as_str().to_owned()).collect();
+        Err(LossyConversion {
+            words: strings(&words),
+            read_back: strings(&read_back),
+            rendered,
+        })
+    }
+
+    /// Convert to another case as a string. When this value was made from a string that is
+    /// already written exactly as it would be in the target case, that string is borrowed, and
+    /// nothing is allocated.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, CamelCase, SnakeCase};
+    /// use std::borrow::Cow;
+    ///
+    /// let snake = SnakeCase::str_as_case("max_size").unwrap();
+    /// assert!(matches!(snake.to_case_cow::<SnakeCase>(), Cow::Borrowed("max_size")));
+    /// assert_eq!("maxSize", snake.to_case_cow::<CamelCase>());
+    /// ```
+    fn to_case_cow<T: Case<'a>>(&self) -> Cow<'a, str> {
+        let options = RenderOptions::default();
+        if let internals::CaseValue::Joined(Cow::Borrowed(source)) = self.case_value() {
+            let source: &'a str = source;
+            let converted = render::DisplayFn(|f: &mut fmt::Formatter| {
+                T::write_words(Self::split_joined(source, ParseOptions::default()), f, &options)
+            });
+            if T::str_is_case(source) && internals::display_eq(source, converted) {
+                return Cow::Borrowed(source);
+            }
+        }
+        let mut converted = String::new();
+        internals::write_converted::<Self, T, _>(self, &mut converted, &options)
+            .expect("writing to a String cannot fail");
+        Cow::Owned(converted)
+    }
+
+    /// Write this value in another case. The words are written as they are read from the source,
+    /// without collecting them or the result, so nothing is allocated for most words.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, CamelCase, SnakeCase};
+    ///
+    /// let mut json = String::from("{\"");
+    /// SnakeCase::str_as_case("max_size").unwrap().write_as::<CamelCase, _>(&mut json).unwrap();
+    /// json.push_str("\":1}");
+    /// assert_eq!(r#"{"maxSize":1}"#, json);
+    /// ```
+    #[inline]
+    fn write_as<T: Case<'a>, W: fmt::Write>(&self, f: &mut W) -> Result<(), fmt::Error> {
+        internals::write_converted::<Self, T, W>(self, f, &RenderOptions::default())
+    }
+
+    /// Like `write_as`, but appends to a `String`, which cannot fail
+    #[inline]
+    fn push_as<T: Case<'a>>(&self, buf: &mut String) {
+        self.write_as::<T, _>(buf).expect("writing to a String cannot fail");
+    }
+
+    /// Like `write_as`, but writes UTF-8 to an `io::Write`. The value is written in many small
+    /// pieces, so `writer` should be buffered.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, KebabCase, PascalCase};
+    ///
+    /// let mut out = Vec::new();
+    /// PascalCase::str_as_case("MaxSize").unwrap().write_as_io::<KebabCase, _>(&mut out).unwrap();
+    /// assert_eq!(b"max-size", &out[..]);
+    /// ```
+    fn write_as_io<T: Case<'a>, W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut writer = render::IoWriter::new(writer);
+        self.write_as::<T, _>(&mut writer).map_err(|fmt::Error| {
+            writer.error.take().unwrap_or_else(|| io::Error::other("formatter error"))
+        })
+    }
+
+    /// The exact length in bytes of this value written in another case, for sizing a buffer
+    /// before calling `write_as`. Nothing is allocated to find it for most words.
+    ///
+    /// ```
+    /// use camel_kebab::{Case, CamelCase, ScreamingSnakeCase};
+    ///
+    /// let camel = CamelCase::str_as_case("maxSize").unwrap();
+    /// let mut buf = String::with_capacity(camel.len_as::<ScreamingSnakeCase>());
+    /// camel.push_as::<ScreamingSnakeCase>(&mut buf);
+    /// assert_eq!("MAX_SIZE", buf);
+    /// assert_eq!(8, camel.len_as::<ScreamingSnakeCase>());
+    /// ```
+    fn len_as<T: Case<'a>>(&self) -> usize {
+        let mut counter = render::CountingWriter::default();
+        self.write_as::<T, _>(&mut counter).expect("counting cannot fail");
+        counter.0
+    }
+
+    /// Like `str_as_case`, but with control over how the words of `source` are found
+    #[inline]
+    fn str_as_case_with_options(source: &'a str, options: ParseOptions) -> Option<Self> {
+        if Self::str_is_case(source) {
+            Some(Self::from_cased_words(Self::split_joined(source, options).collect()))
+        } else {
+            None
+        }
+    }
 }
 
-trait CaseExt {
+/// Methods on `str` for every case. There is also a trait for each case, such as
+/// `SnakeCaseExt`, with methods named after it.
+///
+/// ```
+/// use camel_kebab::{CamelCaseExt, CaseExt, KebabCase, SnakeCase, SnakeCaseExt};
+///
+/// assert!("max_size".is_case::<SnakeCase>());
+/// assert!(!"maxSize".is_snake_case());
+/// assert_eq!("max-size", KebabCase::from("max_size".as_snake_case().unwrap()).to_string());
+/// assert_eq!("max_size", "MaxSize".to_snake_case());
+/// assert_eq!("xmlHttpRequest", "XML-http_request".to_camel_case());
+/// ```
+pub trait CaseExt {
     fn is_case<'a, C: Case<'a>>(&self) -> bool;
     fn as_case<'a, C: Case<'a>>(&'a self) -> Option<C>;
     fn as_case_unchecked<'a, C: Case<'a>>(&'a self) -> C;
+    /// Convert a string in any case, or a mixture of cases, to case `C`. The words are found as
+    /// by `WordList::parse_lenient`.
+    fn to_case<'a, C>(&'a self) -> String
+    where
+        C: Case<'a> + From<WordList<'a>> + fmt::Display;
 }
 
 impl CaseExt for str {
@@ -57,12 +317,58 @@ impl CaseExt for str {
     fn as_case_unchecked<'a, C: Case<'a>>(&'a self) -> C {
         C::str_as_case_unchecked(self)
     }
+
+    #[inline]
+    fn to_case<'a, C>(&'a self) -> String
+    where
+        C: Case<'a> + From<WordList<'a>> + fmt::Display,
+    {
+        C::from(WordList::parse_lenient(self)).to_string()
+    }
 }
 
-impl_from!(CamelCase, KebabCase, PascalCase => SnakeCase);
-impl_from!(KebabCase, PascalCase, SnakeCase => CamelCase);
-impl_from!(PascalCase, SnakeCase, CamelCase => KebabCase);
-impl_from!(SnakeCase, CamelCase, KebabCase => PascalCase);
+impl_from_each_other!(
+    CamelCase,
+    KebabCase,
+    PascalCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    TrainCase,
+    HttpHeaderCase,
+    CobolCase,
+    AdaCase,
+    FlatCase,
+    UpperFlatCase
+);
+impl_from_delimited!(
+    CamelCase,
+    KebabCase,
+    PascalCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    TrainCase,
+    HttpHeaderCase,
+    CobolCase,
+    AdaCase,
+    FlatCase,
+    UpperFlatCase
+);
+impl_from_word_list!(
+    CamelCase,
+    KebabCase,
+    PascalCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    TrainCase,
+    HttpHeaderCase,
+    CobolCase,
+    AdaCase,
+    FlatCase,
+    UpperFlatCase
+);
+
+#[cfg(test)]
+mod conformance;
 
 #[cfg(test)]
 mod tests {
@@ -79,4 +385,658 @@ mod tests {
     fn test_camel_no_match() {
         assert_eq!(None, "ThisIsNotCamelCase".as_case::<CamelCase>());
     }
+
+    #[test]
+    fn test_snake_no_match() {
+        assert_eq!(None, "this-is-not-snake-case".as_case::<SnakeCase>());
+        assert!("this_is_snake_case".is_case::<SnakeCase>());
+    }
+
+    #[test]
+    fn test_screaming_snake_into() {
+        let screaming: ScreamingSnakeCase = "MAX_BUFFER_SIZE".as_case_unchecked();
+        let camel: CamelCase = screaming.clone().into();
+        assert_eq!("maxBufferSize", format!("{}", camel));
+        let kebab: KebabCase = screaming.into();
+        assert_eq!("max-buffer-size", format!("{}", kebab));
+    }
+
+    #[test]
+    fn test_screaming_snake_from() {
+        let pascal: PascalCase = "ThisWasPascalCase".as_case_unchecked();
+        let screaming: ScreamingSnakeCase = pascal.into();
+        assert_eq!("THIS_WAS_PASCAL_CASE", format!("{}", screaming));
+        assert_eq!(Some(screaming), "THIS_WAS_PASCAL_CASE".as_case());
+    }
+
+    #[test]
+    fn test_screaming_snake_no_match() {
+        assert_eq!(None, "NOT_Screaming".as_case::<ScreamingSnakeCase>());
+        assert_eq!(None, "_LEADING".as_case::<ScreamingSnakeCase>());
+        assert_eq!(None, "not_screaming".as_case::<ScreamingSnakeCase>());
+    }
+
+    #[test]
+    fn test_train_into() {
+        let kebab: KebabCase = "content-type".as_case_unchecked();
+        let train: TrainCase = kebab.into();
+        assert_eq!("Content-Type", format!("{}", train));
+        let snake: SnakeCase = "Content-Type".as_case::<TrainCase>().unwrap().into();
+        assert_eq!("content_type", format!("{}", snake));
+    }
+
+    #[test]
+    fn test_train_no_match() {
+        assert_eq!(None, "content-type".as_case::<TrainCase>());
+        assert_eq!(None, "Content-TYPE".as_case::<TrainCase>());
+        assert_eq!(None, "Content_Type".as_case::<TrainCase>());
+    }
+
+    #[test]
+    fn test_http_header_canonicalize() {
+        for &(name, expected) in &[
+            ("content-type", "Content-Type"),
+            ("www-authenticate", "WWW-Authenticate"),
+            ("ETAG", "ETag"),
+            ("dnt", "DNT"),
+            ("x-xss-protection", "X-XSS-Protection"),
+            ("sec-websocket-key", "Sec-WebSocket-Key"),
+        ] {
+            let header = HttpHeaderCase::canonicalize(name);
+            assert_eq!(expected, format!("{}", header));
+            assert!(expected.is_case::<HttpHeaderCase>());
+        }
+    }
+
+    #[test]
+    fn test_http_header_no_match() {
+        assert_eq!(None, "Www-Authenticate".as_case::<HttpHeaderCase>());
+        assert_eq!(None, "Etag".as_case::<HttpHeaderCase>());
+        assert_eq!(None, "content-type".as_case::<HttpHeaderCase>());
+    }
+
+    #[test]
+    fn test_http_header_round_trip() {
+        let header: HttpHeaderCase = "X-XSS-Protection".as_case_unchecked();
+        let snake: SnakeCase = header.into();
+        assert_eq!("x_xss_protection", format!("{}", snake));
+        let header: HttpHeaderCase = snake.into();
+        assert_eq!("X-XSS-Protection", format!("{}", header));
+        let train: TrainCase = "ETag".as_case::<HttpHeaderCase>().unwrap().into();
+        assert_eq!("Etag", format!("{}", train));
+    }
+
+    #[test]
+    fn test_dot_case() {
+        let camel: CamelCase = "thisWasCamelCase".as_case_unchecked();
+        let dot: DotCase = camel.into();
+        assert_eq!("this.was.camel.case", format!("{}", dot));
+        assert_eq!(Some(dot), "this.was.camel.case".as_case());
+        let pascal: PascalCase = "some.dotted.name".as_case::<DotCase>().unwrap().into();
+        assert_eq!("SomeDottedName", format!("{}", pascal));
+        assert_eq!(None, "not_dot_case".as_case::<DotCase>());
+        assert_eq!(None, "not..dot.case".as_case::<DotCase>());
+    }
+
+    #[test]
+    fn test_path_case() {
+        let snake: SnakeCase = "src_lib_rs".as_case_unchecked();
+        let path: PathCase = snake.into();
+        assert_eq!("src/lib/rs", format!("{}", path));
+        assert!("/src/lib".as_case::<PathCase>().is_none());
+    }
+
+    #[test]
+    fn test_multi_character_delimiter() {
+        let pascal: PascalCase = "StdCollectionsHashMap".as_case_unchecked();
+        let path: DelimitedCase<DoubleColon> = pascal.into();
+        assert_eq!("std::collections::hash::map", format!("{}", path));
+        let snake: SnakeCase = "std::collections"
+            .as_case::<DelimitedCase<DoubleColon>>()
+            .unwrap()
+            .into();
+        assert_eq!("std_collections", format!("{}", snake));
+        assert!("std:::collections".as_case::<DelimitedCase<DoubleColon>>().is_none());
+
+        let dunder: DelimitedCase<DoubleUnderscore> = "a__b__c".as_case_unchecked();
+        let kebab: KebabCase = dunder.into();
+        assert_eq!("a-b-c", format!("{}", kebab));
+    }
+
+    #[test]
+    fn test_custom_delimiter_casing() {
+        struct UpperDot;
+        impl Delimiter for UpperDot {
+            const SEPARATOR: &'static str = ".";
+            const CASING: WordCasing = WordCasing::Upper;
+        }
+        struct CapitalizedSpace;
+        impl Delimiter for CapitalizedSpace {
+            const SEPARATOR: &'static str = " ";
+            const CASING: WordCasing = WordCasing::Capitalized;
+        }
+
+        let camel: CamelCase = "someValue".as_case_unchecked();
+        let upper: DelimitedCase<UpperDot> = camel.into();
+        assert_eq!("SOME.VALUE", format!("{}", upper));
+        assert!("SOME.VALUE".is_case::<DelimitedCase<UpperDot>>());
+        assert!(!"some.value".is_case::<DelimitedCase<UpperDot>>());
+
+        let title: DelimitedCase<CapitalizedSpace> = "Hello World".as_case().unwrap();
+        let snake: SnakeCase = title.into();
+        assert_eq!("hello_world", format!("{}", snake));
+    }
+
+    #[test]
+    fn test_cobol_case() {
+        let camel: CamelCase = "workingStorage".as_case_unchecked();
+        let cobol: CobolCase = camel.into();
+        assert_eq!("WORKING-STORAGE", format!("{}", cobol));
+        let snake: SnakeCase = "PERFORM-UNTIL-DONE".as_case::<CobolCase>().unwrap().into();
+        assert_eq!("perform_until_done", format!("{}", snake));
+        assert_eq!(None, "WORKING_STORAGE".as_case::<CobolCase>());
+        assert_eq!(None, "Working-Storage".as_case::<CobolCase>());
+    }
+
+    #[test]
+    fn test_ada_case() {
+        let kebab: KebabCase = "text-io".as_case_unchecked();
+        let ada: AdaCase = kebab.into();
+        assert_eq!("Text_Io", format!("{}", ada));
+        let camel: CamelCase = "Unbounded_String".as_case::<AdaCase>().unwrap().into();
+        assert_eq!("unboundedString", format!("{}", camel));
+        assert_eq!(None, "Text_io".as_case::<AdaCase>());
+        assert_eq!(None, "Text-Io".as_case::<AdaCase>());
+        assert_eq!(None, "TEXT_IO".as_case::<AdaCase>());
+    }
+
+    #[test]
+    fn test_flat_case_with_dictionary() {
+        let dictionary = Dictionary::new(["file", "system", "watcher", "max", "buffer", "size"]);
+        let flat = FlatCase::str_as_case_with_dictionary("filesystemwatcher", &dictionary).unwrap();
+        assert_eq!("filesystemwatcher", format!("{}", flat));
+        let pascal: PascalCase = flat.into();
+        assert_eq!("FileSystemWatcher", format!("{}", pascal));
+
+        let upper = UpperFlatCase::str_as_case_with_dictionary("MAXBUFFERSIZE", &dictionary).unwrap();
+        let snake: SnakeCase = upper.into();
+        assert_eq!("max_buffer_size", format!("{}", snake));
+
+        assert!(FlatCase::str_as_case_with_dictionary("NotFlat", &dictionary).is_none());
+    }
+
+    #[test]
+    fn test_flat_case_without_dictionary() {
+        let flat: FlatCase = "username".as_case().unwrap();
+        let pascal: PascalCase = flat.into();
+        assert_eq!("Username", format!("{}", pascal));
+
+        let snake: SnakeCase = "some_name".as_case_unchecked();
+        let flat: FlatCase = snake.clone().into();
+        assert_eq!("somename", format!("{}", flat));
+        let upper: UpperFlatCase = snake.into();
+        assert_eq!("SOMENAME", format!("{}", upper));
+        assert!(!"some_name".is_case::<FlatCase>());
+        assert!(!"SOME_NAME".is_case::<UpperFlatCase>());
+    }
+
+    #[test]
+    fn test_acronyms_into() {
+        let pascal: PascalCase = "HTTPServer".as_case_unchecked();
+        let snake: SnakeCase = pascal.into();
+        assert_eq!("http_server", format!("{}", snake));
+
+        let camel: CamelCase = "parseXMLDoc".as_case_unchecked();
+        let screaming: ScreamingSnakeCase = camel.clone().into();
+        assert_eq!("PARSE_XML_DOC", format!("{}", screaming));
+        let pascal: PascalCase = camel.into();
+        assert_eq!("ParseXmlDoc", format!("{}", pascal));
+    }
+
+    #[test]
+    fn test_acronyms_split_letters() {
+        let options = ParseOptions::default().acronyms(AcronymPolicy::SplitLetters);
+        let camel = CamelCase::str_as_case_with_options("parseXMLDoc", options).unwrap();
+        let snake: SnakeCase = camel.into();
+        assert_eq!("parse_x_m_l_doc", format!("{}", snake));
+        assert!(CamelCase::str_as_case_with_options("NotCamel", options).is_none());
+    }
+
+    #[test]
+    fn test_digit_policy() {
+        let start_word = ParseOptions::default().digits(DigitPolicy::StartWord);
+        let standalone = ParseOptions::default().digits(DigitPolicy::Standalone);
+
+        let pascal: PascalCase = "Vec3".as_case_unchecked();
+        assert_eq!("vec3", format!("{}", SnakeCase::from(pascal)));
+        let pascal = PascalCase::str_as_case_with_options("Vec3", standalone).unwrap();
+        assert_eq!("vec_3", format!("{}", SnakeCase::from(pascal)));
+
+        let camel = CamelCase::str_as_case_with_options("i18nKey", standalone).unwrap();
+        assert_eq!("i-18-n-key", format!("{}", KebabCase::from(camel)));
+        let camel = CamelCase::str_as_case_with_options("utf8Decoder", start_word).unwrap();
+        assert_eq!("UTF_8_DECODER", format!("{}", ScreamingSnakeCase::from(camel)));
+
+        let screaming = ScreamingSnakeCase::str_as_case_with_options("IPV4_ADDR", start_word).unwrap();
+        assert_eq!("ipv-4-addr", format!("{}", KebabCase::from(screaming)));
+        let snake: SnakeCase = "ipv4_addr".as_case_unchecked();
+        assert_eq!("ipv4-addr", format!("{}", KebabCase::from(snake)));
+
+        let header = HttpHeaderCase::str_as_case_with_options("Content-MD5", standalone).unwrap();
+        assert_eq!("content_md5", format!("{}", SnakeCase::from(header)));
+    }
+
+    struct CapitalizedDot;
+
+    impl Delimiter for CapitalizedDot {
+        const SEPARATOR: &'static str = ".";
+        const CASING: WordCasing = WordCasing::Capitalized;
+    }
+
+    #[test]
+    fn test_digit_policy_capitalized_words() {
+        // Splitting on digits can leave a piece of a capitalized word that begins in lowercase
+        for (policy, expected) in [
+            (DigitPolicy::Standalone, "Vec2DX"),
+            (DigitPolicy::StartWord, "Vec2dX"),
+        ] {
+            let options = ParseOptions::default().digits(policy);
+            let train = TrainCase::str_as_case_with_options("Vec2d-X", options).unwrap();
+            assert_eq!(expected, PascalCase::from(train).to_string());
+            let ada = AdaCase::str_as_case_with_options("Vec2d_X", options).unwrap();
+            assert_eq!(expected, PascalCase::from(ada).to_string());
+            let header = HttpHeaderCase::str_as_case_with_options("Vec2d-X", options).unwrap();
+            assert_eq!(expected, PascalCase::from(header).to_string());
+            let dot = DelimitedCase::<CapitalizedDot>::str_as_case_with_options("Vec2d.X", options).unwrap();
+            assert_eq!(expected, PascalCase::from(dot).to_string());
+        }
+    }
+
+    #[test]
+    fn test_render_exceptions() {
+        let snake: SnakeCase = "xml_http_request".as_case_unchecked();
+        let pascal: PascalCase = snake.into();
+        assert_eq!("XmlHttpRequest", pascal.to_string());
+
+        let go = Exceptions::go_initialisms();
+        let options = RenderOptions::default().exceptions(&go);
+        assert_eq!("XMLHTTPRequest", pascal.display_with(options).to_string());
+        let camel: CamelCase = pascal.into();
+        assert_eq!("xmlHTTPRequest", camel.display_with(options).to_string());
+        let kebab: KebabCase = camel.into();
+        assert_eq!("xml-http-request", kebab.display_with(options).to_string());
+    }
+
+    #[test]
+    fn test_render_exceptions_joined() {
+        let go = Exceptions::go_initialisms();
+        let options = RenderOptions::default().exceptions(&go);
+        let pascal: PascalCase = "UserIdUrl".as_case().unwrap();
+        assert_eq!("UserIDURL", pascal.display_with(options).to_string());
+        let header: HttpHeaderCase = "X-Request-Id".as_case().unwrap();
+        assert_eq!("X-Request-ID", header.display_with(options).to_string());
+    }
+
+    #[test]
+    fn test_render_exceptions_brand_names() {
+        let mut exceptions = Exceptions::new();
+        exceptions.insert("iPhone");
+        exceptions.insert_lowercase("iPhone");
+        exceptions.insert("GitHub");
+        let options = RenderOptions::default().exceptions(&exceptions);
+
+        let snake: SnakeCase = "github_iphone_app".as_case_unchecked();
+        let pascal: PascalCase = snake.clone().into();
+        assert_eq!("GitHubiPhoneApp", pascal.display_with(options).to_string());
+        let camel: CamelCase = "iphone_github".as_case_unchecked::<SnakeCase>().into();
+        assert_eq!("iPhoneGitHub", camel.display_with(options).to_string());
+        assert_eq!("github_iphone_app", snake.display_with(options).to_string());
+    }
+
+    #[test]
+    fn test_render_exceptions_ascii() {
+        let mut exceptions = Exceptions::new();
+        exceptions.insert_lowercase("iPhone");
+        exceptions.insert_lowercase("\u{E9}Cole");
+        let options = RenderOptions::default().exceptions(&exceptions);
+        let ascii = options.ascii();
+
+        let camel: CamelCase = "\u{EF}phone_app".as_case::<SnakeCase>().unwrap().into();
+        assert_eq!("iPhoneApp", camel.display_with(ascii).to_string());
+        let camel: CamelCase = "\u{E9}cole_app".as_case::<SnakeCase>().unwrap().into();
+        assert_eq!("\u{E9}ColeApp", camel.display_with(options).to_string());
+        assert_eq!("ecoleApp", camel.display_with(ascii).to_string());
+    }
+
+    #[test]
+    fn test_render_locale() {
+        let snake: SnakeCase = "istanbul_ili".as_case_unchecked();
+        let screaming: ScreamingSnakeCase = snake.into();
+        assert_eq!("ISTANBUL_ILI", screaming.to_string());
+        let turkish = RenderOptions::default().locale(Locale::Turkish);
+        assert_eq!("İSTANBUL_İLİ", screaming.display_with(turkish).to_string());
+
+        let screaming: ScreamingSnakeCase = "DIŞ_KAPI".as_case().unwrap();
+        let kebab: KebabCase = screaming.into();
+        assert_eq!("dış-kapı", kebab.display_with(turkish).to_string());
+
+        let greek = RenderOptions::default().locale(Locale::Greek);
+        let snake: SnakeCase = "αθήνα_οδός".as_case_unchecked();
+        let screaming: ScreamingSnakeCase = snake.into();
+        assert_eq!("ΑΘΗΝΑ_ΟΔΟΣ", screaming.display_with(greek).to_string());
+        let pascal: PascalCase = "ΟΔΌΣ".as_case::<UpperFlatCase>().unwrap().into();
+        assert_eq!("Οδό\u{3C2}", pascal.display_with(greek).to_string());
+    }
+
+    #[test]
+    fn test_render_titlecase_digraphs() {
+        let snake: SnakeCase = "ǆungla_walk".as_case_unchecked();
+        let pascal: PascalCase = snake.into();
+        let options = RenderOptions::default().locale(Locale::Root);
+        assert_eq!("ǅunglaWalk", pascal.display_with(options).to_string());
+        let train: TrainCase = pascal.into();
+        assert_eq!("ǅungla-Walk", train.display_with(options).to_string());
+    }
+
+    #[test]
+    fn test_script_boundaries() {
+        let options = ParseOptions::default().scripts(ScriptPolicy::Split);
+        let flat = FlatCase::str_as_case_with_options("서울station", options).unwrap();
+        assert_eq!("서울_station", SnakeCase::from(flat).to_string());
+        let kebab = KebabCase::str_as_case_with_options("東京tower-東京タワー", options).unwrap();
+        assert_eq!("東京_tower_東京タワー", SnakeCase::from(kebab).to_string());
+        let flat: FlatCase = "서울station".as_case().unwrap();
+        assert_eq!("서울station", SnakeCase::from(flat).to_string());
+    }
+
+    #[test]
+    fn test_to_identifier() {
+        let kebab: KebabCase = "match".as_case().unwrap();
+        let snake: SnakeCase = kebab.into();
+        assert_eq!("r#match", snake.to_identifier(Language::Rust).ident);
+        let pascal: PascalCase = "class".as_case::<SnakeCase>().unwrap().into();
+        assert_eq!("Class", pascal.to_identifier(Language::Python).ident);
+        assert!(!pascal.to_identifier(Language::Python).escaped);
+        let camel: CamelCase = "select".as_case::<SnakeCase>().unwrap().into();
+        let escaped = camel.to_identifier(Language::Sql);
+        assert_eq!(("\"select\"", true), (&*escaped.ident, escaped.escaped));
+        let camel: CamelCase = "user_name".as_case_unchecked::<SnakeCase>().into();
+        assert_eq!("userName", camel.to_identifier(Language::TypeScript).ident);
+    }
+
+    #[test]
+    fn test_render_ascii() {
+        let snake: SnakeCase = "straße_øl_crème".as_case_unchecked();
+        let kebab: KebabCase = snake.into();
+        let options = RenderOptions::default().ascii();
+        assert_eq!("strasse-ol-creme", kebab.display_with(options).to_string());
+        let pascal: PascalCase = kebab.into();
+        assert_eq!("StrasseOlCreme", pascal.display_with(options).to_string());
+        let kebab: KebabCase = "ærø-købing".as_case().unwrap();
+        assert_eq!("aero-kobing", kebab.display_with(options).to_string());
+    }
+
+    fn error(result: Result<(), CaseError>) -> (usize, char, CaseErrorKind) {
+        let error = result.unwrap_err();
+        (error.offset, error.ch, error.kind)
+    }
+
+    #[test]
+    fn test_validate_delimited() {
+        use CaseErrorKind::*;
+        assert!(SnakeCase::validate("max_size").is_ok());
+        assert_eq!((4, 'S', Uppercase), error(SnakeCase::validate("max_Size")));
+        assert_eq!((0, '_', LeadingDelimiter), error(SnakeCase::validate("_max")));
+        assert_eq!((3, '_', TrailingDelimiter), error(SnakeCase::validate("max_")));
+        assert_eq!((4, '_', RepeatedDelimiter), error(SnakeCase::validate("max__size")));
+        assert_eq!((3, '-', NonAlphanumeric), error(SnakeCase::validate("max-size")));
+        assert_eq!((0, '_', LeadingDelimiter), error(SnakeCase::validate("_")));
+        assert_eq!((1, 'a', Lowercase), error(ScreamingSnakeCase::validate("Max_SIZE")));
+        assert_eq!((4, 's', Lowercase), error(TrainCase::validate("Max-size")));
+        assert_eq!((5, 'I', Uppercase), error(AdaCase::validate("Max_SIze")));
+        assert_eq!((5, ':', RepeatedDelimiter), error(DelimitedCase::<DoubleColon>::validate("std::::fmt")));
+        assert_eq!((3, ':', TrailingDelimiter), error(DelimitedCase::<DoubleColon>::validate("std::")));
+        assert!(DotCase::validate("cafe\u{301}.au").is_ok());
+        assert_eq!((5, '\u{301}', NonAlphanumeric), error(DotCase::validate("cafe.\u{301}")));
+    }
+
+    #[test]
+    fn test_validate_humps() {
+        use CaseErrorKind::*;
+        assert!(CamelCase::validate("maxSize").is_ok());
+        assert_eq!((0, 'M', Uppercase), error(CamelCase::validate("MaxSize")));
+        assert_eq!((0, 'm', Lowercase), error(PascalCase::validate("maxSize")));
+        assert!(CamelCase::validate("1st").is_ok());
+        assert!(PascalCase::validate("東京Tower").is_ok());
+        assert_eq!((3, '_', NonAlphanumeric), error(CamelCase::validate("max_size")));
+        assert_eq!((0, '_', NonAlphanumeric), error(PascalCase::validate("_Max")));
+        assert_eq!((3, 'S', Uppercase), error(FlatCase::validate("maxSize")));
+        assert_eq!((1, 'a', Lowercase), error(UpperFlatCase::validate("MaxSIZE")));
+    }
+
+    #[test]
+    fn test_validate_header() {
+        use CaseErrorKind::*;
+        assert!(HttpHeaderCase::validate("If-None-Match").is_ok());
+        assert_eq!((1, 't', Lowercase), error(HttpHeaderCase::validate("Etag")));
+        assert_eq!((3, 'w', Lowercase), error(HttpHeaderCase::validate("X-Www-Authenticate")));
+        assert_eq!((8, 'm', Lowercase), error(HttpHeaderCase::validate("If-None-match")));
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let snake = SnakeCase::try_parse("max_size").unwrap();
+        assert_eq!("maxSize", CamelCase::from(snake).to_string());
+        let error = CamelCase::try_parse("max size").unwrap_err();
+        assert_eq!("non-alphanumeric character ' ' at byte 3", error.to_string());
+        assert_eq!(Err(error), CaseKind::Camel.validate("max size"));
+    }
+
+    #[test]
+    fn test_try_convert_lossy() {
+        let snake: SnakeCase = "io_2_x".as_case_unchecked();
+        let error = snake.clone().try_convert::<CamelCase>().unwrap_err();
+        assert_eq!("io2X", error.rendered);
+        assert_eq!(vec!["io", "2", "x"], error.words);
+        assert_eq!(vec!["io2", "X"], error.read_back);
+        assert_eq!(
+            r#""io2X" is read back as the words ["io2", "X"], not ["io", "2", "x"]"#,
+            error.to_string()
+        );
+        assert!(snake.clone().try_convert::<PascalCase>().is_err());
+        assert!(snake.clone().try_convert::<FlatCase>().is_err());
+        assert_eq!("IO_2_X", snake.try_convert::<ScreamingSnakeCase>().unwrap().to_string());
+
+        let snake: SnakeCase = "max_size".as_case_unchecked();
+        assert!(snake.clone().try_convert::<FlatCase>().is_err());
+        assert_eq!("maxSize", snake.try_convert::<CamelCase>().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_try_convert_acronyms() {
+        // The words are the same, even though the acronym is not capitalized the same way
+        let pascal: PascalCase = "XMLHttpRequest".as_case().unwrap();
+        let snake = pascal.try_convert::<SnakeCase>().unwrap();
+        assert_eq!("xml_http_request", snake.to_string());
+        assert_eq!("XmlHttpRequest", snake.try_convert::<PascalCase>().unwrap().to_string());
+
+        // Single letter words run together into an acronym
+        let snake: SnakeCase = "a_b_c".as_case_unchecked();
+        let error = snake.try_convert::<CamelCase>().unwrap_err();
+        assert_eq!("aBC", error.rendered);
+        assert_eq!(vec!["a", "BC"], error.read_back);
+    }
+
+    #[test]
+    fn test_to_case_cow() {
+        use std::borrow::Cow;
+
+        let snake: SnakeCase = "max_size".as_case().unwrap();
+        assert!(matches!(snake.to_case_cow::<SnakeCase>(), Cow::Borrowed("max_size")));
+        assert!(matches!(snake.to_case_cow::<CamelCase>(), Cow::Owned(ref s) if s == "maxSize"));
+
+        // Already valid Pascal case, but not written the way Pascal case writes these words
+        let pascal: PascalCase = "XMLHttpRequest".as_case().unwrap();
+        assert!(matches!(pascal.to_case_cow::<PascalCase>(), Cow::Owned(ref s) if s == "XmlHttpRequest"));
+
+        // A single word is valid in both cases, and written the same way
+        let kebab: KebabCase = "max".as_case().unwrap();
+        assert!(matches!(kebab.to_case_cow::<SnakeCase>(), Cow::Borrowed("max")));
+
+        // Converted values hold words rather than a string to borrow
+        let camel = CamelCase::from(snake);
+        assert!(matches!(camel.to_case_cow::<CamelCase>(), Cow::Owned(ref s) if s == "maxSize"));
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let source = String::from("max_size");
+        let snake: SnakeCase<'static> = SnakeCase::str_as_case(&source).unwrap().into_owned();
+        let camel: CamelCase<'static> = CamelCase::from(snake.clone()).into_owned();
+        let path: PathCase<'static> = PathCase::from(snake.clone()).into_owned();
+        drop(source);
+        assert_eq!("max_size", snake.to_string());
+        assert_eq!("maxSize", camel.to_string());
+        assert_eq!("max/size", path.to_string());
+        assert_eq!("MAX-SIZE", CobolCase::from(camel).to_string());
+    }
+
+    #[test]
+    fn test_into_owned_keeps_words() {
+        let dictionary = Dictionary::new(["file", "system", "watcher"]);
+        let source = String::from("filesystemwatcher");
+        let flat = FlatCase::str_as_case_with_dictionary(&source, &dictionary).unwrap().into_owned();
+        drop(source);
+        assert_eq!("FileSystemWatcher", PascalCase::from(flat).to_string());
+
+        let snake: SnakeCase = "io_2_x".as_case().unwrap();
+        let camel = CamelCase::from(snake).into_owned();
+        assert_eq!("io_2_x", SnakeCase::from(camel).to_string());
+    }
+
+    fn word_kinds<'a, C: Case<'a>>(case: &C) -> Vec<(String, WordKind)> {
+        case.words().map(|word| (word.to_string(), word.kind())).collect()
+    }
+
+    #[test]
+    fn test_words() {
+        let pascal: PascalCase = "XMLHttpRequest".as_case().unwrap();
+        let expected = vec![
+            ("XML".to_owned(), WordKind::Acronym),
+            ("Http".to_owned(), WordKind::Capitalized),
+            ("Request".to_owned(), WordKind::Capitalized),
+        ];
+        assert_eq!(expected, word_kinds(&pascal));
+
+        let screaming: ScreamingSnakeCase = "MAX_SIZE_2".as_case().unwrap();
+        let kinds: Vec<_> = screaming.words().map(|word| word.kind()).collect();
+        assert_eq!(vec![WordKind::Upper, WordKind::Upper, WordKind::Numeric], kinds);
+
+        let header: HttpHeaderCase = "WWW-Authenticate".as_case().unwrap();
+        let kinds: Vec<_> = header.words().map(|word| word.kind()).collect();
+        assert_eq!(vec![WordKind::Acronym, WordKind::Capitalized], kinds);
+
+        // A converted value keeps the words as they were written in its source
+        let snake = SnakeCase::from(pascal);
+        assert_eq!(expected, word_kinds(&snake));
+        assert_eq!("xml_http_request", snake.to_string());
+
+        let words = WordList::parse_lenient("max-Size iPhone");
+        let kinds: Vec<_> = words.words().map(|word| word.kind()).collect();
+        assert_eq!(vec![WordKind::Lower, WordKind::Capitalized, WordKind::Lower, WordKind::Capitalized], kinds);
+        assert_eq!(0, SnakeCase::str_as_case("").unwrap().words().count());
+    }
+
+    #[test]
+    fn test_word_rendering() {
+        let camel: CamelCase = "parseXMLDoc".as_case().unwrap();
+        let mut output = String::new();
+        for word in camel.words() {
+            word.write_capitalized(&mut output).unwrap();
+            output.push('.');
+            word.write_with_casing(&mut output, WordCasing::Upper, &RenderOptions::default()).unwrap();
+            output.push(' ');
+        }
+        assert_eq!("Parse.PARSE Xml.XML Doc.DOC ", output);
+    }
+
+    #[test]
+    fn test_str_ext() {
+        assert!("max_size".is_snake_case());
+        assert!("max-size".as_kebab_case().is_some());
+        assert!("max_size".as_kebab_case().is_none());
+        assert_eq!("MaxSize", "MaxSize".as_pascal_case_unchecked().to_string());
+        assert!("Content-Type".is_http_header_case());
+        assert!("max/size".is_path_case());
+    }
+
+    #[test]
+    fn test_to_case_from_anything() {
+        for source in &["max_size", "maxSize", "MaxSize", "MAX-SIZE", "max.size", "__max size__"] {
+            assert_eq!("max_size", source.to_snake_case());
+            assert_eq!("maxSize", source.to_camel_case());
+            assert_eq!("max-size", source.to_kebab_case());
+            assert_eq!("MaxSize", source.to_pascal_case());
+            assert_eq!("MAX_SIZE", source.to_screaming_snake_case());
+            assert_eq!("Max-Size", source.to_train_case());
+            assert_eq!("MAX-SIZE", source.to_cobol_case());
+            assert_eq!("Max_Size", source.to_ada_case());
+            assert_eq!("maxsize", source.to_flat_case());
+            assert_eq!("MAXSIZE", source.to_upper_flat_case());
+            assert_eq!("max.size", source.to_dot_case());
+            assert_eq!("max/size", source.to_path_case());
+        }
+        assert_eq!("xml_http_request", "XMLHttpRequest".to_snake_case());
+        assert_eq!("Content-MD5", "content_md5".to_http_header_case());
+        assert_eq!("max::size", "maxSize".to_case::<DelimitedCase<DoubleColon>>());
+        assert_eq!("", "--".to_camel_case());
+    }
+
+    #[test]
+    fn test_write_as() {
+        let pascal: PascalCase = "XMLHttpRequest".as_case().unwrap();
+        let mut output = String::from("key=");
+        pascal.write_as::<SnakeCase, _>(&mut output).unwrap();
+        assert_eq!("key=xml_http_request", output);
+        assert_eq!(16, pascal.len_as::<SnakeCase>());
+
+        // Values that hold words are written from them
+        let snake = SnakeCase::from(pascal);
+        let mut output = String::new();
+        snake.push_as::<TrainCase>(&mut output);
+        assert_eq!("Xml-Http-Request", output);
+        assert_eq!(output.len(), snake.len_as::<TrainCase>());
+
+        // Final sigma, which depends on the position in the word
+        let screaming: ScreamingSnakeCase = "ΣΟΦΟΣ_ΟΔΟΣ".as_case().unwrap();
+        let mut output = String::new();
+        screaming.push_as::<PascalCase>(&mut output);
+        assert_eq!("\u{3A3}\u{3BF}\u{3C6}\u{3BF}\u{3C2}\u{39F}\u{3B4}\u{3BF}\u{3C2}", output);
+        assert_eq!(output.len(), screaming.len_as::<PascalCase>());
+        assert_eq!(output, PascalCase::from(screaming).to_string());
+        assert_eq!("\u{39F}\u{3C2}", "\u{39F}\u{3A3}".to_pascal_case());
+    }
+
+    struct FailingWriter;
+
+    impl io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_as_io() {
+        let kebab: KebabCase = "content-type".as_case().unwrap();
+        let mut output = Vec::new();
+        kebab.write_as_io::<HttpHeaderCase, _>(&mut output).unwrap();
+        assert_eq!(b"Content-Type", &output[..]);
+
+        let error = kebab.write_as_io::<HttpHeaderCase, _>(&mut FailingWriter).unwrap_err();
+        assert_eq!(io::ErrorKind::BrokenPipe, error.kind());
+    }
 }
@@ -0,0 +1,241 @@
+use crate::internals::split_first_grapheme;
+use std::fmt::{self, Write};
+
+/// Language-specific case mapping rules, for use when rendering a case. Setting a locale also
+/// enables the language-neutral special casing rules of Unicode that depend on context, such as
+/// final sigma.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// No language-specific rules
+    #[default]
+    Root,
+    /// Dotted and dotless i: `i` ↔ `İ` and `ı` ↔ `I`
+    Turkish,
+    /// The same rules as Turkish
+    Azeri,
+    /// Keeps the dot above `i` and `j` when an accent is added in lowercase
+    Lithuanian,
+    /// Removes accents in uppercase
+    Greek,
+}
+
+impl Locale {
+    #[inline]
+    fn has_dotted_i(self) -> bool {
+        self == Locale::Turkish || self == Locale::Azeri
+    }
+}
+
+const COMBINING_DOT_ABOVE: char = '\u{307}';
+
+/// Whether `ch` is an accent that sits above the letter before it
+#[inline]
+fn is_combining_above(ch: char) -> bool {
+    ('\u{300}'..='\u{314}').contains(&ch) || ('\u{33D}'..='\u{344}').contains(&ch)
+}
+
+#[inline]
+fn is_soft_dotted(ch: char) -> bool {
+    matches!(ch, 'i' | 'j' | 'į' | 'ɨ' | 'ⅈ' | 'ⅉ')
+}
+
+#[inline]
+fn is_greek(ch: char) -> bool {
+    ('\u{370}'..='\u{3FF}').contains(&ch) || ('\u{1F00}'..='\u{1FFF}').contains(&ch)
+}
+
+/// Whether a capital sigma at `index` in `word` ends a word, and so should be lowercased to `ς`
+fn is_final_sigma(word: &str, index: usize) -> bool {
+    let before = word[..index].chars().rev().find(|ch| !is_combining(*ch));
+    let after = word[index + 'Σ'.len_utf8()..].chars().find(|ch| !is_combining(*ch));
+    before.is_some_and(char::is_alphabetic) && !after.is_some_and(char::is_alphabetic)
+}
+
+#[inline]
+fn is_combining(ch: char) -> bool {
+    ('\u{300}'..='\u{36F}').contains(&ch)
+}
+
+/// Write `word` in lowercase, starting from the char at `start`. The preceding part of the word
+/// is only used as context.
+pub(crate) fn write_lower_from<W: Write>(
+    f: &mut W,
+    word: &str,
+    start: usize,
+    locale: Locale,
+) -> Result<(), fmt::Error> {
+    let mut chars = word[start..].char_indices().map(|(i, ch)| (start + i, ch)).peekable();
+    while let Some((i, ch)) = chars.next() {
+        let next = chars.peek().map(|&(_, next)| next);
+        match ch {
+            'Σ' if is_final_sigma(word, i) => f.write_char('ς')?,
+            'I' if locale.has_dotted_i() => {
+                if next == Some(COMBINING_DOT_ABOVE) {
+                    chars.next();
+                    f.write_char('i')?;
+                } else {
+                    f.write_char('ı')?;
+                }
+            }
+            'İ' if locale.has_dotted_i() => f.write_char('i')?,
+            'I' | 'J' | 'Į' if locale == Locale::Lithuanian && next.is_some_and(is_combining_above) => {
+                write!(f, "{}", ch.to_lowercase())?;
+                f.write_char(COMBINING_DOT_ABOVE)?;
+            }
+            'Ì' if locale == Locale::Lithuanian => f.write_str("i\u{307}\u{300}")?,
+            'Í' if locale == Locale::Lithuanian => f.write_str("i\u{307}\u{301}")?,
+            'Ĩ' if locale == Locale::Lithuanian => f.write_str("i\u{307}\u{303}")?,
+            _ => write!(f, "{}", ch.to_lowercase())?,
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn write_upper<W: Write>(f: &mut W, word: &str, locale: Locale) -> Result<(), fmt::Error> {
+    let mut prev = None;
+    for ch in word.chars() {
+        match ch {
+            'i' if locale.has_dotted_i() => f.write_char('İ')?,
+            COMBINING_DOT_ABOVE
+                if locale == Locale::Lithuanian && prev.is_some_and(is_soft_dotted) => {}
+            '\u{301}' | '\u{342}' if locale == Locale::Greek && prev.is_some_and(is_greek) => {}
+            _ if locale == Locale::Greek => match greek_upper_without_accent(ch) {
+                Some(upper) => f.write_char(upper)?,
+                None => write!(f, "{}", ch.to_uppercase())?,
+            },
+            _ => write!(f, "{}", ch.to_uppercase())?,
+        }
+        prev = Some(ch);
+    }
+    Ok(())
+}
+
+/// Write the first grapheme cluster of `word` in titlecase and the rest in lowercase
+pub(crate) fn write_title<W: Write>(f: &mut W, word: &str, locale: Locale) -> Result<(), fmt::Error> {
+    let (head, _) = split_first_grapheme(word);
+    let mut chars = head.chars();
+    if let Some(first) = chars.next() {
+        match first {
+            'i' if locale.has_dotted_i() => f.write_char('İ')?,
+            _ => write_titlecase(f, first)?,
+        }
+        for mark in chars {
+            if !(mark == COMBINING_DOT_ABOVE && locale == Locale::Lithuanian && is_soft_dotted(first)) {
+                f.write_char(mark)?;
+            }
+        }
+        write_lower_from(f, word, head.len(), locale)?;
+    }
+    Ok(())
+}
+
+/// Write `ch` in titlecase, which differs from uppercase for some digraphs and ligatures: `ǆ` is
+/// written as `ǅ` and `ß` as `Ss`
+pub(crate) fn write_titlecase<W: Write>(f: &mut W, ch: char) -> Result<(), fmt::Error> {
+    match titlecase(ch) {
+        Some(title) => f.write_str(title),
+        None => write!(f, "{}", ch.to_uppercase()),
+    }
+}
+
+/// Characters whose titlecase mapping differs from their uppercase mapping
+fn titlecase(ch: char) -> Option<&'static str> {
+    let title = match ch {
+        'Ǆ' | 'ǅ' | 'ǆ' => "ǅ",
+        'Ǉ' | 'ǈ' | 'ǉ' => "ǈ",
+        'Ǌ' | 'ǋ' | 'ǌ' => "ǋ",
+        'Ǳ' | 'ǲ' | 'ǳ' => "ǲ",
+        'ß' => "Ss",
+        'ﬀ' => "Ff",
+        'ﬁ' => "Fi",
+        'ﬂ' => "Fl",
+        'ﬃ' => "Ffi",
+        'ﬄ' => "Ffl",
+        'ﬅ' | 'ﬆ' => "St",
+        _ => return None,
+    };
+    Some(title)
+}
+
+fn greek_upper_without_accent(ch: char) -> Option<char> {
+    let upper = match ch {
+        'ά' | 'Ά' => 'Α',
+        'έ' | 'Έ' => 'Ε',
+        'ή' | 'Ή' => 'Η',
+        'ί' | 'Ί' => 'Ι',
+        'ό' | 'Ό' => 'Ο',
+        'ύ' | 'Ύ' => 'Υ',
+        'ώ' | 'Ώ' => 'Ω',
+        'ΐ' => 'Ϊ',
+        'ΰ' => 'Ϋ',
+        _ => return None,
+    };
+    Some(upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lower(word: &str, locale: Locale) -> String {
+        let mut output = String::new();
+        write_lower_from(&mut output, word, 0, locale).unwrap();
+        output
+    }
+
+    fn upper(word: &str, locale: Locale) -> String {
+        let mut output = String::new();
+        write_upper(&mut output, word, locale).unwrap();
+        output
+    }
+
+    fn title(word: &str, locale: Locale) -> String {
+        let mut output = String::new();
+        write_title(&mut output, word, locale).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_turkish() {
+        for &locale in &[Locale::Turkish, Locale::Azeri] {
+            assert_eq!("ıstanbul", lower("ISTANBUL", locale));
+            assert_eq!("istanbul", lower("İSTANBUL", locale));
+            assert_eq!("istanbul", lower("I\u{307}STANBUL", locale));
+            assert_eq!("İSTANBUL", upper("istanbul", locale));
+            assert_eq!("IĞDIR", upper("ığdır", locale));
+            assert_eq!("İzmir", title("izmir", locale));
+        }
+        assert_eq!("istanbul", lower("ISTANBUL", Locale::Root));
+        assert_eq!("ISTANBUL", upper("istanbul", Locale::Root));
+    }
+
+    #[test]
+    fn test_lithuanian() {
+        assert_eq!("i\u{307}\u{300}", lower("Ì", Locale::Lithuanian));
+        assert_eq!("i\u{307}\u{301}s", lower("\u{49}\u{301}S", Locale::Lithuanian));
+        assert_eq!("is", lower("IS", Locale::Lithuanian));
+        assert_eq!("I\u{300}", upper("i\u{307}\u{300}", Locale::Lithuanian));
+        assert_eq!("ì", lower("Ì", Locale::Root));
+    }
+
+    #[test]
+    fn test_greek() {
+        assert_eq!("\u{3C3}οφο\u{3C2}", lower("ΣΟΦΟΣ", Locale::Root));
+        assert_eq!("οδό\u{3C2}", lower("ΟΔΌΣ", Locale::Greek));
+        assert_eq!("\u{3C3}", lower("Σ", Locale::Root));
+        assert_eq!("ΑΘΗΝΑ", upper("Αθήνα", Locale::Greek));
+        assert_eq!("ΑΘΗΝΑ", upper("Αθη\u{301}να", Locale::Greek));
+        assert_eq!("ΑΘΉΝΑ", upper("Αθήνα", Locale::Root));
+    }
+
+    #[test]
+    fn test_titlecase() {
+        assert_eq!("ǅungla", title("ǆungla", Locale::Root));
+        assert_eq!("ǅungla", title("Ǆungla", Locale::Root));
+        assert_eq!("ǈubljana", title("ǉubljana", Locale::Root));
+        assert_eq!("Ssen", title("ßen", Locale::Root));
+        assert_eq!("Hello", title("hELLO", Locale::Root));
+        assert_eq!("E\u{301}cole", title("e\u{301}COLE", Locale::Root));
+        assert_eq!("I\u{300}s", title("i\u{307}\u{300}s", Locale::Lithuanian));
+    }
+}
@@ -3,11 +3,16 @@ macro_rules! impl_str_ext {
     case: $case: ident,
     as: $as_name: ident,
     as_unchecked: $as_unchecked_name: ident,
-    is: $is_name: ident) => {
-        trait $trait: $crate::CaseExt {
+    is: $is_name: ident,
+    to: $to_name: ident) => {
+        #[doc = concat!("Methods on `str` for [`", stringify!($case), "`](crate::", stringify!($case), ")")]
+        pub trait $trait: $crate::CaseExt {
             fn $as_name(&self) -> Option<$case<'_>>;
             fn $as_unchecked_name(&self) -> $case<'_>;
             fn $is_name(&self) -> bool;
+            /// Convert a string in any case, or a mixture of cases, to this case. The words are
+            /// found as by `WordList::parse_lenient`.
+            fn $to_name(&self) -> String;
         }
 
         impl $trait for str {
@@ -25,6 +30,11 @@ macro_rules! impl_str_ext {
             fn $is_name(&self) -> bool {
                 <Self as $crate::CaseExt>::is_case::<$case>(self)
             }
+
+            #[inline]
+            fn $to_name(&self) -> String {
+                <Self as $crate::CaseExt>::to_case::<$case>(self)
+            }
         }
     };
 }
@@ -42,6 +52,18 @@ macro_rules! impl_from {
     }
 }
 
+/// Implements `From` in both directions between every pair of the given cases
+macro_rules! impl_from_each_other {
+    ($head: ident) => {};
+    ($head: ident, $($tail: ident),+) => {
+        $(
+            impl_from!($head => $tail);
+            impl_from!($tail => $head);
+        )+
+        impl_from_each_other!($($tail),+);
+    };
+}
+
 macro_rules! impl_eq {
     ($case: ident) => {
         impl<'a> std::cmp::PartialEq for $case<'a> {
@@ -53,3 +75,54 @@ macro_rules! impl_eq {
         impl<'a> std::cmp::Eq for $case<'a> {}
     };
 }
+
+macro_rules! impl_into_owned {
+    ($case: ident) => {
+        impl<'a> $case<'a> {
+            /// A copy of this value that does not borrow from the string it was made from
+            pub fn into_owned(self) -> $case<'static> {
+                $case(self.0.into_owned())
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_delimited {
+    ($($case: ident),+) => {
+        $(
+            impl<'a, D: $crate::Delimiter> std::convert::From<$case<'a>> for $crate::DelimitedCase<'a, D> {
+                fn from(other: $case<'a>) -> $crate::DelimitedCase<'a, D> {
+                    use $crate::internals::Case;
+                    $crate::DelimitedCase::from_cased_words(other.to_cased_words())
+                }
+            }
+
+            impl<'a, D: $crate::Delimiter> std::convert::From<$crate::DelimitedCase<'a, D>> for $case<'a> {
+                fn from(other: $crate::DelimitedCase<'a, D>) -> $case<'a> {
+                    use $crate::internals::Case;
+                    $case::from_cased_words(other.to_cased_words())
+                }
+            }
+        )+
+    }
+}
+
+macro_rules! impl_from_word_list {
+    ($($case: ident),+) => {
+        $(
+            impl<'a> std::convert::From<$crate::WordList<'a>> for $case<'a> {
+                fn from(words: $crate::WordList<'a>) -> $case<'a> {
+                    use $crate::internals::Case;
+                    $case::from_cased_words(words.0)
+                }
+            }
+
+            impl<'a> std::convert::From<$case<'a>> for $crate::WordList<'a> {
+                fn from(other: $case<'a>) -> $crate::WordList<'a> {
+                    use $crate::internals::Case;
+                    $crate::WordList(other.to_cased_words())
+                }
+            }
+        )+
+    }
+}
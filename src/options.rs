@@ -0,0 +1,77 @@
+/// Controls how the words of a string are found when it is parsed.
+///
+/// ```
+/// use camel_kebab::{AcronymPolicy, Case, ParseOptions, PascalCase, SnakeCase};
+///
+/// let options = ParseOptions::default().acronyms(AcronymPolicy::SplitLetters);
+/// let pascal = PascalCase::str_as_case_with_options("HTTPServer", options).unwrap();
+/// assert_eq!("h_t_t_p_server", SnakeCase::from(pascal).to_string());
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub acronyms: AcronymPolicy,
+    pub digits: DigitPolicy,
+    pub scripts: ScriptPolicy,
+}
+
+impl ParseOptions {
+    #[inline]
+    pub fn acronyms(mut self, acronyms: AcronymPolicy) -> Self {
+        self.acronyms = acronyms;
+        self
+    }
+
+    #[inline]
+    pub fn digits(mut self, digits: DigitPolicy) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    #[inline]
+    pub fn scripts(mut self, scripts: ScriptPolicy) -> Self {
+        self.scripts = scripts;
+        self
+    }
+}
+
+/// How a run of capital letters is split in camel and Pascal case.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum AcronymPolicy {
+    /// A run of capitals is a single word, except for the last capital when a lowercase letter
+    /// follows it: `HTTPServer` is split into `HTTP` and `Server`.
+    #[default]
+    Group,
+    /// Every capital starts a new word: `HTTPServer` is split into `H`, `T`, `T`, `P` and
+    /// `Server`.
+    SplitLetters,
+}
+
+/// Where the word boundaries are around digits. This applies the same way to every case, whether
+/// its words are delimited or not.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DigitPolicy {
+    /// Digits belong to the word before them: `vec3` and `utf8Decoder` are split into `vec3`, and
+    /// `utf8` and `Decoder`.
+    #[default]
+    Attach,
+    /// A run of digits starts a new word, and anything after it stays in that word: `vec3` and
+    /// `i18nKey` are split into `vec` and `3`, and `i`, `18n` and `Key`.
+    StartWord,
+    /// A run of digits is always a word by itself: `i18nKey` is split into `i`, `18`, `n` and
+    /// `Key`.
+    Standalone,
+}
+
+/// Whether a change of writing system is a word boundary. Like `DigitPolicy`, this applies to
+/// every case.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum ScriptPolicy {
+    /// Only case and delimiters separate words: `東京Tower` is a single word.
+    #[default]
+    Ignore,
+    /// A new word starts where the script changes: `東京Tower` is split into `東京` and `Tower`,
+    /// and `서울station` into `서울` and `station`. Characters that are shared between scripts,
+    /// such as digits and combining marks, do not change the script. Han, Hiragana and Katakana
+    /// are treated as one script, since they are mixed within Japanese words.
+    Split,
+}
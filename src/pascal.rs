@@ -1,5 +1,9 @@
-use crate::internals::{self, split_words_on_uppercase, write_pascal_case, CaseValue, Word};
-use crate::Case;
+use crate::internals::{
+    self, hump_word, joined_words, split_words_on_uppercase, validate_humps, write_pascal_case,
+    CaseValue, Word,
+};
+use crate::{Case, CaseError, ParseOptions, RenderOptions};
+use std::borrow::Borrow;
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -7,17 +11,13 @@ pub struct PascalCase<'a>(pub(crate) CaseValue<'a>);
 
 impl<'a> Case<'a> for PascalCase<'a> {
     #[inline]
-    fn str_is_case(source: &str) -> bool {
-        if let Some(first) = source.chars().next() {
-            first.is_uppercase() && source.chars().all(|ch| ch.is_alphanumeric())
-        } else {
-            true
-        }
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_humps(source, true)
     }
 
     #[inline]
     fn str_as_case_unchecked(source: &'a str) -> Self {
-        PascalCase(CaseValue::Joined(source))
+        PascalCase(CaseValue::Joined(source.into()))
     }
 }
 
@@ -31,18 +31,37 @@ impl<'a> internals::Case<'a> for PascalCase<'a> {
     fn to_cased_words(self) -> Vec<Word<'a>> {
         match self.0 {
             CaseValue::Words(words) => words,
-            CaseValue::Joined(string) => split_words_on_uppercase(string)
-                .map(|w| Word::capitalized(w))
-                .collect(),
+            CaseValue::Joined(string) => joined_words::<Self>(string),
         }
     }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        split_words_on_uppercase(source, options).map(hump_word)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        write_pascal_case(words, f, options)
+    }
 }
 
 impl<'a> fmt::Display for PascalCase<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match &self.0 {
             CaseValue::Joined(string) => string.fmt(f),
-            CaseValue::Words(words) => write_pascal_case(words.iter(), f),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
         }
     }
 }
@@ -52,7 +71,10 @@ impl_str_ext! {
     case: PascalCase,
     as: as_pascal_case,
     as_unchecked: as_pascal_case_unchecked,
-    is: is_pascal_case
+    is: is_pascal_case,
+    to: to_pascal_case
 }
 
 impl_eq!(PascalCase);
+
+impl_into_owned!(PascalCase);
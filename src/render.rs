@@ -0,0 +1,90 @@
+use crate::{Exceptions, Locale};
+use std::{fmt, io};
+
+/// Controls how the words of a case are written out.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RenderOptions<'r> {
+    /// Preferred spellings of particular words
+    pub exceptions: Option<&'r Exceptions>,
+    /// Language-specific case mapping. When this is `None`, words are cased with the default
+    /// mappings of `char::to_lowercase` and `char::to_uppercase`, except that the first letter of
+    /// a capitalized word is written in titlecase.
+    pub locale: Option<Locale>,
+    /// Transliterate every word to ASCII letters and digits before casing it, so `straße` is
+    /// written as `strasse` and `Øre` as `Ore`. Anything with no ASCII equivalent is dropped.
+    pub ascii: bool,
+}
+
+impl<'r> RenderOptions<'r> {
+    #[inline]
+    pub fn exceptions(mut self, exceptions: &'r Exceptions) -> Self {
+        self.exceptions = Some(exceptions);
+        self
+    }
+
+    #[inline]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    #[inline]
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    /// Whether these options could change how a string that is already in the right case is
+    /// written
+    #[inline]
+    pub(crate) fn is_plain(&self) -> bool {
+        self.exceptions.is_none() && self.locale.is_none() && !self.ascii
+    }
+}
+
+/// Adapts a formatting closure to `Display`
+pub(crate) struct DisplayFn<F>(pub(crate) F);
+
+impl<F> fmt::Display for DisplayFn<F>
+where
+    F: Fn(&mut fmt::Formatter) -> Result<(), fmt::Error>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        (self.0)(f)
+    }
+}
+
+/// Counts the bytes written to it, without storing them
+#[derive(Default)]
+pub(crate) struct CountingWriter(pub(crate) usize);
+
+impl fmt::Write for CountingWriter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Adapts an `io::Write` to `fmt::Write`. Since `fmt::Error` cannot carry an I/O error, the first
+/// one is kept in `error`.
+pub(crate) struct IoWriter<'w, W> {
+    inner: &'w mut W,
+    pub(crate) error: Option<io::Error>,
+}
+
+impl<'w, W: io::Write> IoWriter<'w, W> {
+    #[inline]
+    pub(crate) fn new(inner: &'w mut W) -> Self {
+        IoWriter { inner, error: None }
+    }
+}
+
+impl<'w, W: io::Write> fmt::Write for IoWriter<'w, W> {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
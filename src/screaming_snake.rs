@@ -0,0 +1,77 @@
+use crate::internals::{joined_words, split_delimited, validate_delimited, self, write_upper_delimited, CaseValue, Word};
+use crate::{Case, CaseError, ParseOptions, RenderOptions, WordCasing};
+use std::borrow::Borrow;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct ScreamingSnakeCase<'a>(pub(crate) CaseValue<'a>);
+
+impl<'a> Case<'a> for ScreamingSnakeCase<'a> {
+    #[inline]
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_delimited(source, "_", WordCasing::Upper)
+    }
+
+    #[inline]
+    fn str_as_case_unchecked(source: &'a str) -> Self {
+        ScreamingSnakeCase(CaseValue::Joined(source.into()))
+    }
+}
+
+impl<'a> internals::Case<'a> for ScreamingSnakeCase<'a> {
+    #[inline]
+    fn from_cased_words(words: Vec<Word<'a>>) -> Self {
+        ScreamingSnakeCase(CaseValue::Words(words))
+    }
+
+    #[inline]
+    fn to_cased_words(self) -> Vec<Word<'a>> {
+        match self.0 {
+            CaseValue::Words(words) => words,
+            CaseValue::Joined(string) => joined_words::<Self>(string),
+        }
+    }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        split_delimited(source, "_", options).map(Word::upper_case)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        write_upper_delimited(words, f, '_', options)
+    }
+}
+
+impl<'a> fmt::Display for ScreamingSnakeCase<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match &self.0 {
+            CaseValue::Joined(string) => string.fmt(f),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
+        }
+    }
+}
+
+impl_str_ext! {
+    trait: ScreamingSnakeCaseExt,
+    case: ScreamingSnakeCase,
+    as: as_screaming_snake_case,
+    as_unchecked: as_screaming_snake_case_unchecked,
+    is: is_screaming_snake_case,
+    to: to_screaming_snake_case
+}
+
+impl_eq!(ScreamingSnakeCase);
+
+impl_into_owned!(ScreamingSnakeCase);
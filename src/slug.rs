@@ -0,0 +1,154 @@
+use crate::internals::transliterate;
+use crate::WordList;
+use std::collections::HashMap;
+
+/// Turn free-form text into an ASCII kebab case slug, suitable for URLs and file names. The text
+/// is split into words as by `WordList::parse_prose`, and each word is transliterated to ASCII.
+///
+/// ```
+/// use camel_kebab::slugify;
+///
+/// assert_eq!("creme-brulee-recipes", slugify("Crème Brûlée — Recipes!"));
+/// assert_eq!("grosse-strasse", slugify("Große Straße"));
+/// ```
+pub fn slugify(source: &str) -> String {
+    slugify_words(source, usize::MAX)
+}
+
+/// Like `slugify`, but the slug is at most `max_len` bytes long. It is cut between words where
+/// possible, and a single word is only cut if it is longer than `max_len` by itself.
+///
+/// ```
+/// use camel_kebab::slugify_with_limit;
+///
+/// assert_eq!("the-quick-brown", slugify_with_limit("The quick brown fox", 17));
+/// ```
+pub fn slugify_with_limit(source: &str, max_len: usize) -> String {
+    slugify_words(source, max_len)
+}
+
+fn slugify_words(source: &str, max_len: usize) -> String {
+    let mut slug = String::new();
+    for word in WordList::parse_prose(source).0 {
+        let mut word = transliterate(word.as_str());
+        if word.is_empty() {
+            continue;
+        }
+        word.make_ascii_lowercase();
+        if slug.is_empty() {
+            // Every char is ASCII, so this is a char boundary
+            word.truncate(max_len);
+        } else if slug.len() + 1 + word.len() > max_len {
+            break;
+        } else {
+            slug.push('-');
+        }
+        slug.push_str(&word);
+    }
+    slug
+}
+
+/// Generates heading anchors the same way as GitHub does for Markdown documents, including the
+/// numeric suffixes that make repeated headings unique. Unlike `slugify`, letters are not
+/// transliterated, and whitespace is not collapsed.
+///
+/// ```
+/// use camel_kebab::GitHubSlugger;
+///
+/// let mut slugger = GitHubSlugger::new();
+/// assert_eq!("whats-new-in-v20", slugger.slug("What's new in v2.0?"));
+/// assert_eq!("usage", slugger.slug("Usage"));
+/// assert_eq!("usage-1", slugger.slug("Usage"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GitHubSlugger {
+    occurrences: HashMap<String, usize>,
+}
+
+impl GitHubSlugger {
+    pub fn new() -> GitHubSlugger {
+        GitHubSlugger::default()
+    }
+
+    /// The anchor for the next heading with the text `heading`
+    pub fn slug(&mut self, heading: &str) -> String {
+        let original = github_slug(heading);
+        let mut slug = original.clone();
+        while self.occurrences.contains_key(&slug) {
+            let count = self.occurrences.entry(original.clone()).or_insert(0);
+            *count += 1;
+            slug = format!("{}-{}", original, count);
+        }
+        self.occurrences.insert(slug.clone(), 0);
+        slug
+    }
+
+    /// Forget the headings seen so far, as at the start of a new document
+    pub fn reset(&mut self) {
+        self.occurrences.clear();
+    }
+}
+
+/// A heading anchor, without the suffix for repeated headings: lowercase, with punctuation and
+/// symbols other than `-` and `_` removed, and each space replaced by `-`
+fn github_slug(heading: &str) -> String {
+    heading
+        .chars()
+        .filter(|&ch| ch.is_alphanumeric() || is_mark(ch) || matches!(ch, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .map(|ch| if ch == ' ' { '-' } else { ch })
+        .collect()
+}
+
+#[inline]
+fn is_mark(ch: char) -> bool {
+    ('\u{300}'..='\u{36F}').contains(&ch)
+        || ('\u{1AB0}'..='\u{1AFF}').contains(&ch)
+        || ('\u{1DC0}'..='\u{1DFF}').contains(&ch)
+        || ('\u{20D0}'..='\u{20FF}').contains(&ch)
+        || ('\u{FE20}'..='\u{FE2F}').contains(&ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!("hello-world-it-s-5-p-m", slugify("Hello, world! It's 5 p.m."));
+        assert_eq!("cafe-menu-v2", slugify("Café — Menu (v2)"));
+        assert_eq!("strasse-ol-aeroskobing", slugify("Straße Øl Ærøskøbing"));
+        assert_eq!("bei-jing", slugify("北京"));
+        assert_eq!("", slugify("🎉 !!"));
+        assert_eq!("party-time", slugify("🎉 party time"));
+    }
+
+    #[test]
+    fn test_slugify_with_limit() {
+        assert_eq!("the-quick", slugify_with_limit("The quick brown fox", 10));
+        assert_eq!("the-quick-brown-fox", slugify_with_limit("The quick brown fox", 19));
+        assert_eq!("supercal", slugify_with_limit("Supercalifragilistic words", 8));
+        assert_eq!("", slugify_with_limit("Anything", 0));
+    }
+
+    #[test]
+    fn test_github_slug() {
+        assert_eq!("getting-started", github_slug("Getting Started"));
+        assert_eq!("api--reference", github_slug("API / Reference"));
+        assert_eq!("café-au-lait", github_slug("Café au lait"));
+        assert_eq!("snake_case-and-kebab-case", github_slug("snake_case and kebab-case"));
+        assert_eq!("-emoji", github_slug("🎉 Emoji"));
+        assert_eq!("", github_slug("???"));
+    }
+
+    #[test]
+    fn test_github_slugger_duplicates() {
+        let mut slugger = GitHubSlugger::new();
+        assert_eq!("foo", slugger.slug("Foo"));
+        assert_eq!("foo-1", slugger.slug("foo"));
+        assert_eq!("foo-1-1", slugger.slug("Foo 1"));
+        assert_eq!("foo-2", slugger.slug("Foo"));
+        slugger.reset();
+        assert_eq!("foo", slugger.slug("Foo"));
+    }
+}
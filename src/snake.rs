@@ -1,5 +1,6 @@
-use crate::internals::{is_lower_case_delimited, self, write_lower_delimited, CaseValue, Word};
-use crate::Case;
+use crate::internals::{joined_words, split_delimited, validate_delimited, self, write_lower_delimited, CaseValue, Word};
+use crate::{Case, CaseError, ParseOptions, RenderOptions, WordCasing};
+use std::borrow::Borrow;
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -7,13 +8,13 @@ pub struct SnakeCase<'a>(pub(crate) CaseValue<'a>);
 
 impl<'a> Case<'a> for SnakeCase<'a> {
     #[inline]
-    fn str_is_case(source: &str) -> bool {
-        is_lower_case_delimited(source, '-')
+    fn validate(source: &str) -> Result<(), CaseError> {
+        validate_delimited(source, "_", WordCasing::Lower)
     }
 
     #[inline]
     fn str_as_case_unchecked(source: &'a str) -> Self {
-        SnakeCase(CaseValue::Joined(source))
+        SnakeCase(CaseValue::Joined(source.into()))
     }
 }
 
@@ -27,16 +28,37 @@ impl<'a> internals::Case<'a> for SnakeCase<'a> {
     fn to_cased_words(self) -> Vec<Word<'a>> {
         match self.0 {
             CaseValue::Words(words) => words,
-            CaseValue::Joined(string) => string.split('_').map(|w| Word::lower_case(w)).collect(),
+            CaseValue::Joined(string) => joined_words::<Self>(string),
         }
     }
+
+    #[inline]
+    fn split_joined<'s>(source: &'s str, options: ParseOptions) -> impl Iterator<Item = Word<'s>> {
+        split_delimited(source, "_", options).map(Word::lower_case)
+    }
+
+    #[inline]
+    fn case_value(&self) -> &CaseValue<'a> {
+        &self.0
+    }
+
+    fn write_words<'w, W, I>(words: I, f: &mut W, options: &RenderOptions) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: Borrow<Word<'w>>,
+    {
+        write_lower_delimited(words, f, '_', options)
+    }
 }
 
 impl<'a> fmt::Display for SnakeCase<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match &self.0 {
             CaseValue::Joined(string) => string.fmt(f),
-            CaseValue::Words(words) => write_lower_delimited(words.iter(), f, '_'),
+            CaseValue::Words(words) => {
+                <Self as internals::Case>::write_words(words, f, &RenderOptions::default())
+            }
         }
     }
 }
@@ -46,7 +68,10 @@ impl_str_ext! {
     case: SnakeCase,
     as: as_snake_case,
     as_unchecked: as_snake_case_unchecked,
-    is: is_snake_case
+    is: is_snake_case,
+    to: to_snake_case
 }
 
 impl_eq!(SnakeCase);
+
+impl_into_owned!(SnakeCase);
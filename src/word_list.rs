@@ -0,0 +1,195 @@
+use crate::internals::{self, cased_word, split_words_on_digits, split_words_on_uppercase, Word};
+use crate::{DelimitedCase, Delimiter, ParseOptions};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Words that are not in any particular case. This can be converted to and from every case.
+#[derive(Debug, Clone, Default)]
+pub struct WordList<'a>(pub(crate) Vec<Word<'a>>);
+
+impl<'a> WordList<'a> {
+    /// Split a string into words, whatever case or mixture of cases it is in. Any character that
+    /// is not alphanumeric is treated as a delimiter, and repeated delimiters are ignored, as are
+    /// delimiters at the start or end. The text between them is split wherever the case changes,
+    /// as for camel case.
+    ///
+    /// ```
+    /// use camel_kebab::{SnakeCase, WordList};
+    ///
+    /// let words = WordList::parse_lenient("__foo-bar_baz Qux--XMLHttp__");
+    /// assert_eq!("foo_bar_baz_qux_xml_http", SnakeCase::from(words).to_string());
+    /// ```
+    pub fn parse_lenient(source: &'a str) -> WordList<'a> {
+        WordList::parse_lenient_with_options(source, ParseOptions::default())
+    }
+
+    pub fn parse_lenient_with_options(source: &'a str, options: ParseOptions) -> WordList<'a> {
+        let words = source
+            .split(|ch: char| !ch.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .flat_map(|word| split_words_on_uppercase(word, options))
+            .map(cased_word)
+            .collect();
+        WordList(words)
+    }
+
+    /// Split free-form text into words, as defined by Unicode word segmentation (UAX #29).
+    /// Whitespace, punctuation and symbols between words are dropped. Punctuation that UAX #29
+    /// allows inside a word, such as the apostrophe of `it's` or the periods of `p.m.`, separates
+    /// words in the same way as a hyphen does, since a case has no way to write it. Words are not
+    /// split where the case changes, so `iPhone` stays one word.
+    ///
+    /// ```
+    /// use camel_kebab::{SnakeCase, WordList};
+    ///
+    /// let words = WordList::parse_prose("Hello, world! It's 5 p.m.");
+    /// assert_eq!("hello_world_it_s_5_p_m", SnakeCase::from(words).to_string());
+    /// ```
+    pub fn parse_prose(source: &'a str) -> WordList<'a> {
+        WordList::parse_prose_with_options(source, ParseOptions::default())
+    }
+
+    pub fn parse_prose_with_options(source: &'a str, options: ParseOptions) -> WordList<'a> {
+        let words = source
+            .unicode_words()
+            .flat_map(|word| word.split(is_word_punctuation))
+            .filter(|word| !word.is_empty())
+            .flat_map(|word| split_words_on_digits(word, options))
+            .map(cased_word)
+            .collect();
+        WordList(words)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The words of this list, in order
+    #[inline]
+    pub fn words(&self) -> impl Iterator<Item = Word<'_>> {
+        self.0.iter().map(Word::reborrow)
+    }
+}
+
+/// Punctuation that can appear inside a word in UAX #29, such as apostrophes, periods and
+/// connectors like `_`. Other characters inside a word are letters, digits or marks.
+#[inline]
+fn is_word_punctuation(ch: char) -> bool {
+    ch.is_ascii_punctuation()
+        || matches!(
+            ch,
+            '\u{B7}' | '\u{387}' | '\u{55F}' | '\u{5F4}' | '\u{2018}' | '\u{2019}' | '\u{2024}'
+                | '\u{2027}' | '\u{203F}' | '\u{2040}' | '\u{2054}' | '\u{FE13}' | '\u{FE33}'
+                | '\u{FE34}' | '\u{FE4D}'..='\u{FE55}' | '\u{FF07}' | '\u{FF0C}' | '\u{FF0E}'
+                | '\u{FF1A}' | '\u{FF1B}' | '\u{FF3F}'
+        )
+}
+
+impl<'a, D: Delimiter> From<WordList<'a>> for DelimitedCase<'a, D> {
+    fn from(words: WordList<'a>) -> DelimitedCase<'a, D> {
+        use crate::internals::Case;
+        DelimitedCase::from_cased_words(words.0)
+    }
+}
+
+impl<'a, D: Delimiter> From<DelimitedCase<'a, D>> for WordList<'a> {
+    fn from(other: DelimitedCase<'a, D>) -> WordList<'a> {
+        use crate::internals::Case;
+        WordList(other.to_cased_words())
+    }
+}
+
+impl<'a> PartialEq for WordList<'a> {
+    /// Word lists are equal if they have the same words, regardless of how each word is cased
+    fn eq(&self, other: &WordList<'a>) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(&other.0).all(|(a, b)| internals::words_eq(a, b))
+    }
+}
+
+impl<'a> Eq for WordList<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CamelCase, DigitPolicy, KebabCase, PascalCase, SnakeCase};
+
+    fn words(source: &str) -> Vec<String> {
+        WordList::parse_lenient(source).0.iter().map(|word| word.as_str().to_owned()).collect()
+    }
+
+    #[test]
+    fn test_parse_lenient_mixed_delimiters() {
+        assert_eq!(vec!["foo", "bar", "baz", "Qux"], words("foo-bar_baz Qux"));
+        assert_eq!(vec!["some", "Mixed", "Thing"], words("some.Mixed_Thing"));
+    }
+
+    #[test]
+    fn test_parse_lenient_repeated_delimiters() {
+        assert_eq!(vec!["init"], words("__init__"));
+        assert_eq!(vec!["Foo", "Bar"], words("Foo--Bar"));
+        assert_eq!(vec!["a", "b"], words(" a , b "));
+    }
+
+    #[test]
+    fn test_parse_lenient_case_changes() {
+        assert_eq!(vec!["parse", "XML", "Doc", "now"], words("parseXMLDoc_now"));
+        assert_eq!(vec!["MAX", "SIZE"], words("MAX_SIZE"));
+    }
+
+    #[test]
+    fn test_parse_lenient_empty() {
+        assert!(WordList::parse_lenient("").is_empty());
+        assert!(WordList::parse_lenient("-_- ").is_empty());
+    }
+
+    #[test]
+    fn test_parse_lenient_options() {
+        let options = ParseOptions::default().digits(DigitPolicy::Standalone);
+        let words = WordList::parse_lenient_with_options("vec3 Utils", options);
+        assert_eq!("vec-3-utils", KebabCase::from(words).to_string());
+    }
+
+    fn prose(source: &str) -> Vec<String> {
+        WordList::parse_prose(source).0.iter().map(|word| word.as_str().to_owned()).collect()
+    }
+
+    #[test]
+    fn test_parse_prose_punctuation() {
+        assert_eq!(vec!["Hello", "world", "It", "s", "5", "p", "m"], prose("Hello, world! It's 5 p.m."));
+        assert_eq!(vec!["Café", "Menu", "v2"], prose("Café — Menu (v2)"));
+        assert_eq!(vec!["Cafe\u{301}", "au", "lait"], prose("Cafe\u{301} au lait..."));
+        assert!(prose(" -- !? ").is_empty());
+    }
+
+    #[test]
+    fn test_parse_prose_apostrophes_and_hyphens() {
+        assert_eq!(prose("don't"), prose("don’t"));
+        assert_eq!(vec!["don", "t"], prose("don’t"));
+        assert_eq!(vec!["well", "known", "fact"], prose("well-known fact"));
+        assert_eq!(vec!["snake", "case", "name"], prose("snake_case name"));
+        assert_eq!(vec!["3", "14"], prose("3.14"));
+    }
+
+    #[test]
+    fn test_parse_prose_into_any_case() {
+        let words = WordList::parse_prose("Café — Menu (v2)");
+        assert_eq!("café-menu-v2", KebabCase::from(words.clone()).to_string());
+        assert_eq!("CaféMenuV2", PascalCase::from(words).to_string());
+        let words = WordList::parse_prose("Buy the new iPhone today");
+        assert_eq!("buyTheNewIphoneToday", CamelCase::from(words).to_string());
+    }
+
+    #[test]
+    fn test_parse_lenient_into_any_case() {
+        let words = WordList::parse_lenient("user-entered Name_v2");
+        assert_eq!("user_entered_name_v2", SnakeCase::from(words.clone()).to_string());
+        assert_eq!("userEnteredNameV2", CamelCase::from(words.clone()).to_string());
+        assert_eq!("UserEnteredNameV2", PascalCase::from(words).to_string());
+    }
+}